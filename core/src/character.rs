@@ -0,0 +1,117 @@
+use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+use bevy::reflect::prelude::*;
+
+/// Component that moves a kinematic body by sliding along the surfaces it hits
+///
+/// The entity should have a [`RigidBody::KinematicPositionBased`](crate::RigidBody) and a
+/// [`CollisionShape`]. Once per frame in which at least one physics step is performed, the desired
+/// `translation` is consumed: the collision shape is cast along it against the rest of the world,
+/// and the body is moved as far as possible, sliding along the surfaces it hits.
+///
+/// The collision shapes attached to the body of the character (including the ones of its children)
+/// are ignored. If the character penetrates an obstacle, it is first pushed out of it, and it cannot
+/// move further into it.
+///
+/// The result of the movement is reported in the [`CharacterControllerOutput`] component, which is
+/// inserted on the entity by the controller.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::KinematicPositionBased)
+///         .insert(CollisionShape::Capsule { half_segment: 0.5, radius: 0.5 })
+///         .insert(CharacterController::default());
+/// }
+///
+/// fn walk(mut characters: Query<&mut CharacterController>) {
+///     for mut character in characters.iter_mut() {
+///         character.translation = Some(Vec3::new(0.1, -0.1, 0.0));
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct CharacterController {
+    /// Translation to apply at the next physics step
+    ///
+    /// It is reset to `None` once applied.
+    pub translation: Option<Vec3>,
+
+    /// Up direction of the character, in world-space
+    pub up: Vec3,
+
+    /// Distance to keep between the collision shape of the character and the obstacles
+    pub skin_width: f32,
+
+    /// Maximum angle (in radians) between a surface normal and the `up` direction, for the surface
+    /// to be considered as ground
+    ///
+    /// The character cannot climb steeper slopes.
+    pub max_slope_angle: f32,
+
+    /// Maximum height of the obstacles that the character automatically steps onto
+    ///
+    /// Use `0.0` to disable stepping.
+    pub max_step_height: f32,
+
+    /// Maximum distance at which the character is snapped down to the ground, when it was grounded
+    /// and doesn't move up
+    ///
+    /// Use `0.0` to disable the ground snapping.
+    pub snap_to_ground: f32,
+
+    /// Maximum number of shape casts to resolve a movement
+    pub max_iterations: u32,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            translation: None,
+            up: Vec3::Y,
+            skin_width: 0.01,
+            max_slope_angle: std::f32::consts::FRAC_PI_4,
+            max_step_height: 0.25,
+            snap_to_ground: 0.2,
+            max_iterations: 4,
+        }
+    }
+}
+
+/// Result of the last movement of a [`CharacterController`]
+#[derive(Debug, Clone, Default)]
+pub struct CharacterControllerOutput {
+    /// Translation that was effectively applied to the character
+    pub effective_translation: Vec3,
+
+    /// Whether the character stands on the ground
+    pub grounded: bool,
+
+    /// Normal of the ground, if the character is grounded
+    pub ground_normal: Option<Vec3>,
+
+    /// Collision shape entity of the ground, if the character is grounded
+    pub ground_entity: Option<Entity>,
+
+    /// Obstacles hit during the movement
+    pub hits: Vec<CharacterHit>,
+}
+
+/// Obstacle hit by a [`CharacterController`]
+#[derive(Debug, Clone)]
+pub struct CharacterHit {
+    /// Collision shape entity that was hit
+    pub entity: Entity,
+
+    /// Normal of the surface that was hit, pointing toward the character, in world-space
+    pub normal: Vec3,
+
+    /// Contact point with the obstacle, in world-space
+    ///
+    /// `None` if the character was already penetrating the obstacle.
+    pub point: Option<Vec3>,
+}
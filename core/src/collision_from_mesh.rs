@@ -0,0 +1,426 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, VertexAttributeValues};
+use bevy::render::pipeline::PrimitiveTopology;
+use bevy::utils::HashSet;
+
+use crate::{CollisionShape, ConvexDecompositionParameters};
+
+/// Component that generates the [`CollisionShape`] of the entity from a bevy [`Mesh`] asset
+///
+/// The collision shape is inserted as soon as the mesh asset is loaded, and updated whenever the
+/// asset is modified (e.g. hot-reloaded).
+///
+/// Requires the `collision-from-mesh` feature.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let mesh = asset_server.load("level.gltf#Mesh0/Primitive0");
+///
+///     commands.spawn_bundle(todo!("Spawn your mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(ColliderFromMesh {
+///             mesh,
+///             kind: MeshColliderKind::TriMesh,
+///         });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColliderFromMesh {
+    /// The mesh to generate the collision shape from
+    pub mesh: Handle<Mesh>,
+
+    /// The kind of collision shape to generate
+    pub kind: MeshColliderKind,
+}
+
+/// Kind of [`CollisionShape`] generated by a [`ColliderFromMesh`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MeshColliderKind {
+    /// Generates a [`CollisionShape::ConvexHull`] of the mesh vertices
+    ConvexHull,
+
+    /// Generates a [`CollisionShape::TriMesh`] with the mesh vertices and triangles
+    ///
+    /// The mesh must use the [`PrimitiveTopology::TriangleList`] topology.
+    TriMesh,
+
+    /// Generates a [`CollisionShape::ConvexDecomposition`] of the mesh, which (unlike
+    /// [`MeshColliderKind::TriMesh`]) can be used for dynamic bodies
+    ///
+    /// The mesh must use the [`PrimitiveTopology::TriangleList`] topology.
+    ConvexDecomposition(ConvexDecompositionParameters),
+}
+
+/// Inserts the collision shapes of the entities whose mesh is loaded or modified
+///
+/// Entities are retried every frame until their mesh is loaded. When the mesh cannot be
+/// converted into the requested kind of shape, a warning is logged once and the entity isn't
+/// retried until its [`ColliderFromMesh`] or its mesh changes.
+#[allow(clippy::type_complexity)]
+pub(crate) fn insert_collision_shapes(
+    mut commands: Commands<'_>,
+    meshes: Res<'_, Assets<Mesh>>,
+    mut mesh_events: EventReader<'_, AssetEvent<Mesh>>,
+    mut unsupported: Local<'_, HashSet<Entity>>,
+    removed: RemovedComponents<'_, ColliderFromMesh>,
+    pending: Query<
+        '_,
+        (Entity, ChangeTrackers<ColliderFromMesh>),
+        Or<(Without<CollisionShape>, Changed<ColliderFromMesh>)>,
+    >,
+    all: Query<'_, (Entity, &ColliderFromMesh, Option<&CollisionShape>)>,
+) {
+    for entity in removed.iter() {
+        unsupported.remove(&entity);
+    }
+
+    // An entity may be both pending and concerned by an asset event, but its shape must be
+    // inserted only once
+    let mut entities: Vec<Entity> = Vec::new();
+
+    for (entity, changes) in pending.iter() {
+        if changes.is_changed() {
+            unsupported.remove(&entity);
+        }
+        if !unsupported.contains(&entity) {
+            entities.push(entity);
+        }
+    }
+
+    for event in mesh_events.iter() {
+        let (handle, modified) = match event {
+            AssetEvent::Created { handle } => (handle, false),
+            AssetEvent::Modified { handle } => (handle, true),
+            AssetEvent::Removed { .. } => continue,
+        };
+
+        // The shapes of the created meshes are usually already inserted by the pending query,
+        // so only the modified meshes replace existing shapes
+        for (entity, collider_from_mesh, shape) in all.iter() {
+            if collider_from_mesh.mesh == *handle && (modified || shape.is_none()) {
+                unsupported.remove(&entity);
+                if !entities.contains(&entity) {
+                    entities.push(entity);
+                }
+            }
+        }
+    }
+
+    for (entity, collider_from_mesh, _) in entities.into_iter().filter_map(|e| all.get(e).ok()) {
+        let kind = collider_from_mesh.kind;
+
+        // The entity is retried at the next frame if the mesh isn't loaded yet
+        if let Some(mesh) = meshes.get(&collider_from_mesh.mesh) {
+            if let Some(shape) = shape_from_mesh(mesh, kind) {
+                commands.entity(entity).insert(shape);
+            } else {
+                warn!("Cannot generate a {kind:?} collision shape from the mesh of {entity:?}");
+                unsupported.insert(entity);
+            }
+        }
+    }
+}
+
+fn shape_from_mesh(mesh: &Mesh, kind: MeshColliderKind) -> Option<CollisionShape> {
+    let vertices: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float3(positions) => {
+            positions.iter().copied().map(Vec3::from).collect()
+        }
+        VertexAttributeValues::Float2(positions) => positions
+            .iter()
+            .map(|[x, y]| Vec3::new(*x, *y, 0.0))
+            .collect(),
+        _ => return None,
+    };
+
+    match kind {
+        MeshColliderKind::ConvexHull => Some(CollisionShape::ConvexHull {
+            points: vertices,
+            border_radius: None,
+        }),
+        MeshColliderKind::TriMesh => Some(CollisionShape::TriMesh {
+            indices: triangles(mesh, vertices.len())?,
+            vertices,
+        }),
+        MeshColliderKind::ConvexDecomposition(parameters) => {
+            Some(CollisionShape::ConvexDecomposition {
+                indices: triangles(mesh, vertices.len())?,
+                vertices,
+                parameters,
+            })
+        }
+    }
+}
+
+fn triangles(mesh: &Mesh, vertex_count: usize) -> Option<Vec<[u32; 3]>> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let indices: Vec<u32> = match mesh.indices() {
+        Some(Indices::U16(indices)) => indices.iter().copied().map(u32::from).collect(),
+        Some(Indices::U32(indices)) => indices.clone(),
+        None => (0..vertex_count as u32).collect(),
+    };
+
+    Some(
+        indices
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::{AssetPlugin, HandleId};
+    use bevy::core::CorePlugin;
+    use bevy::reflect::TypeRegistryArc;
+
+    use super::*;
+
+    fn test_app() -> App {
+        let mut builder = App::build();
+        builder
+            .init_resource::<TypeRegistryArc>()
+            .add_plugin(CorePlugin)
+            .add_plugin(AssetPlugin)
+            .add_asset::<Mesh>()
+            .add_system_to_stage(CoreStage::PreUpdate, insert_collision_shapes.system());
+        builder.app
+    }
+
+    fn lines() -> Mesh {
+        let mut lines = Mesh::new(PrimitiveTopology::LineList);
+        lines.set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]],
+        );
+        lines
+    }
+
+    fn spawn(app: &mut App, mesh: Handle<Mesh>, kind: MeshColliderKind) -> Entity {
+        app.world
+            .spawn()
+            .insert(ColliderFromMesh { mesh, kind })
+            .id()
+    }
+
+    fn mesh() -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 1.0, 0.0],
+            ],
+        );
+        mesh.set_indices(Some(Indices::U16(vec![0, 1, 2, 1, 3, 2])));
+        mesh
+    }
+
+    #[test]
+    fn convex_hull_from_mesh() {
+        let shape = shape_from_mesh(&mesh(), MeshColliderKind::ConvexHull);
+
+        if let Some(CollisionShape::ConvexHull { points, .. }) = shape {
+            assert_eq!(points.len(), 4);
+            assert_eq!(points[3], Vec3::new(1.0, 1.0, 0.0));
+        } else {
+            panic!("Expected a convex hull, got {:?}", shape);
+        }
+    }
+
+    #[test]
+    fn trimesh_from_mesh() {
+        let shape = shape_from_mesh(&mesh(), MeshColliderKind::TriMesh);
+
+        if let Some(CollisionShape::TriMesh { vertices, indices }) = shape {
+            assert_eq!(vertices.len(), 4);
+            assert_eq!(indices, vec![[0, 1, 2], [1, 3, 2]]);
+        } else {
+            panic!("Expected a triangle mesh, got {:?}", shape);
+        }
+    }
+
+    #[test]
+    fn trimesh_from_mesh_without_indices() {
+        let mut mesh = mesh();
+        mesh.set_indices(None);
+
+        let shape = shape_from_mesh(&mesh, MeshColliderKind::TriMesh);
+
+        if let Some(CollisionShape::TriMesh { indices, .. }) = shape {
+            assert_eq!(indices, vec![[0, 1, 2]]);
+        } else {
+            panic!("Expected a triangle mesh, got {:?}", shape);
+        }
+    }
+
+    #[test]
+    fn no_trimesh_from_mesh_with_other_topology() {
+        assert!(shape_from_mesh(&lines(), MeshColliderKind::TriMesh).is_none());
+    }
+
+    #[test]
+    fn convex_decomposition_from_mesh() {
+        let parameters = ConvexDecompositionParameters {
+            resolution: 32,
+            concavity: 0.5,
+        };
+
+        let shape = shape_from_mesh(&mesh(), MeshColliderKind::ConvexDecomposition(parameters));
+
+        if let Some(CollisionShape::ConvexDecomposition {
+            vertices,
+            indices,
+            parameters: actual_parameters,
+        }) = shape
+        {
+            assert_eq!(vertices.len(), 4);
+            assert_eq!(indices, vec![[0, 1, 2], [1, 3, 2]]);
+            assert_eq!(actual_parameters, parameters);
+        } else {
+            panic!("Expected a convex decomposition, got {:?}", shape);
+        }
+    }
+
+    #[test]
+    fn waits_until_the_mesh_is_loaded() {
+        let mut app = test_app();
+        let handle_id = HandleId::random::<Mesh>();
+        let entity = spawn(&mut app, Handle::weak(handle_id), MeshColliderKind::TriMesh);
+
+        app.update();
+        assert!(app.world.get::<CollisionShape>(entity).is_none());
+
+        app.world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .set_untracked(handle_id, mesh());
+        app.update();
+
+        assert!(matches!(
+            app.world.get::<CollisionShape>(entity),
+            Some(CollisionShape::TriMesh { .. })
+        ));
+    }
+
+    #[test]
+    fn shape_is_not_inserted_again_when_the_created_event_arrives() {
+        let mut app = test_app();
+        let handle = app
+            .world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .add(mesh());
+        let entity = spawn(&mut app, handle, MeshColliderKind::TriMesh);
+
+        app.update();
+        assert!(app.world.get::<CollisionShape>(entity).is_some());
+
+        // Replace the shape, to detect whether it is inserted again
+        app.world
+            .entity_mut(entity)
+            .insert(CollisionShape::Sphere { radius: 1.0 });
+        app.update();
+
+        assert!(matches!(
+            app.world.get::<CollisionShape>(entity),
+            Some(CollisionShape::Sphere { .. })
+        ));
+    }
+
+    #[test]
+    fn shape_is_updated_when_the_mesh_is_modified() {
+        let mut app = test_app();
+        let handle = app
+            .world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .add(mesh());
+        let entity = spawn(&mut app, handle.clone(), MeshColliderKind::ConvexHull);
+
+        app.update();
+        app.update();
+
+        let mut modified = mesh();
+        modified.set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]],
+        );
+        modified.set_indices(None);
+        app.world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .set_untracked(handle, modified);
+
+        // The asset event is sent at the end of the frame, and read at the next one
+        app.update();
+        app.update();
+
+        if let Some(CollisionShape::ConvexHull { points, .. }) = app.world.get(entity) {
+            assert_eq!(points.len(), 3);
+            assert_eq!(points[1], Vec3::new(2.0, 0.0, 0.0));
+        } else {
+            panic!("Expected a convex hull");
+        }
+    }
+
+    #[test]
+    fn unsupported_mesh_is_retried_when_the_collider_from_mesh_changes() {
+        let mut app = test_app();
+        let handle = app
+            .world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .add(lines());
+        let entity = spawn(&mut app, handle, MeshColliderKind::TriMesh);
+
+        app.update();
+        app.update();
+        assert!(app.world.get::<CollisionShape>(entity).is_none());
+
+        app.world.get_mut::<ColliderFromMesh>(entity).unwrap().kind = MeshColliderKind::ConvexHull;
+        app.update();
+
+        assert!(matches!(
+            app.world.get::<CollisionShape>(entity),
+            Some(CollisionShape::ConvexHull { .. })
+        ));
+    }
+
+    #[test]
+    fn unsupported_mesh_is_retried_when_the_mesh_is_modified() {
+        let mut app = test_app();
+        let handle = app
+            .world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .add(lines());
+        let entity = spawn(&mut app, handle.clone(), MeshColliderKind::TriMesh);
+
+        app.update();
+        app.update();
+        assert!(app.world.get::<CollisionShape>(entity).is_none());
+
+        app.world
+            .get_resource_mut::<Assets<Mesh>>()
+            .unwrap()
+            .set_untracked(handle, mesh());
+        app.update();
+        app.update();
+
+        assert!(matches!(
+            app.world.get::<CollisionShape>(entity),
+            Some(CollisionShape::TriMesh { .. })
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::reflect::prelude::*;
+
+/// Component that defines the linear and angular damping of a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// Damping slows the body down over time, like a drag force. A value of `0.0` (the default) means
+/// no damping, and higher values make the velocity decrease faster.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Damping::from_linear(0.5).with_angular(0.2));
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct Damping {
+    /// Damping of the linear velocity
+    pub linear: f32,
+
+    /// Damping of the angular velocity
+    pub angular: f32,
+}
+
+impl Damping {
+    /// Returns a linear damping (without angular damping)
+    #[must_use]
+    pub fn from_linear(linear: f32) -> Self {
+        Self {
+            linear,
+            angular: 0.0,
+        }
+    }
+
+    /// Returns an angular damping (without linear damping)
+    #[must_use]
+    pub fn from_angular(angular: f32) -> Self {
+        Self {
+            linear: 0.0,
+            angular,
+        }
+    }
+
+    /// Returns a new version with the given linear damping
+    #[must_use]
+    pub fn with_linear(mut self, linear: f32) -> Self {
+        self.linear = linear;
+        self
+    }
+
+    /// Returns a new version with the given angular damping
+    #[must_use]
+    pub fn with_angular(mut self, angular: f32) -> Self {
+        self.angular = angular;
+        self
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::reflect::prelude::*;
+
+/// Component that defines the dominance group of a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// When two dynamic bodies are in contact, the one with the highest dominance acts as if it had an
+/// infinite mass: it pushes the other body but is never pushed by it. Bodies with the same dominance
+/// interact normally.
+///
+/// The default dominance is `0`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Dominance(1)); // Push other bodies, but is never pushed by them
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Reflect)]
+pub struct Dominance(pub i8);
@@ -1,9 +1,13 @@
 use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
 
 use crate::CollisionLayers;
 
 /// An event fired when the collision state between two entities changed
 ///
+/// For contacts between non-sensor shapes, a [`ContactDetails`] event is fired along with each
+/// [`CollisionEvent::Started`], with the contact points, normal and impulse.
+///
 /// # Example
 ///
 /// ```
@@ -31,6 +35,53 @@ pub enum CollisionEvent {
     Stopped(CollisionData, CollisionData),
 }
 
+/// An event fired alongside each [`CollisionEvent::Started`] between two non-sensor collision
+/// shapes, describing how they are in contact
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn_impact_effects(mut events: EventReader<ContactDetails>) {
+///     for details in events.iter() {
+///         for point in details.points() {
+///             println!("Impact at {:?} along {:?}", point.point1, details.normal())
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactDetails {
+    data1: CollisionData,
+    data2: CollisionData,
+    normal: Vec3,
+    points: Vec<ContactPoint>,
+}
+
+/// A contact point between two collision shapes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContactPoint {
+    /// Location of the contact point on the first collision shape, in world space
+    pub point1: Vec3,
+
+    /// Location of the contact point on the second collision shape, in world space
+    pub point2: Vec3,
+
+    /// Contact normal at this point in world space, pointing from the first shape toward the second
+    ///
+    /// The points may have different normals, when the shapes touch at several places.
+    pub normal: Vec3,
+
+    /// How deep the two shapes penetrate each other at this point
+    ///
+    /// It is negative if the shapes are close, but not touching.
+    pub penetration_depth: f32,
+
+    /// Impulse applied along the contact normal to push the shapes apart
+    pub impulse: f32,
+}
+
 /// Collision data concerning one of the two entity that collided
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct CollisionData {
@@ -130,3 +181,77 @@ impl CollisionData {
         self.collision_layers
     }
 }
+
+impl ContactDetails {
+    #[must_use]
+    #[allow(missing_docs)]
+    pub fn new(
+        data1: CollisionData,
+        data2: CollisionData,
+        normal: Vec3,
+        points: Vec<ContactPoint>,
+    ) -> Self {
+        Self {
+            data1,
+            data2,
+            normal,
+            points,
+        }
+    }
+
+    /// Returns the data for the two entities in contact
+    ///
+    /// The data is in the same order as in the matching [`CollisionEvent::Started`]
+    #[must_use]
+    pub fn data(&self) -> (CollisionData, CollisionData) {
+        (self.data1, self.data2)
+    }
+
+    /// Returns the entities containing the [`CollisionShape`](crate::CollisionShape) in contact
+    #[must_use]
+    pub fn collision_shape_entities(&self) -> (Entity, Entity) {
+        (
+            self.data1.collision_shape_entity,
+            self.data2.collision_shape_entity,
+        )
+    }
+
+    /// Returns the entities containing the [`RigidBody`](crate::RigidBody) in contact
+    #[must_use]
+    pub fn rigid_body_entities(&self) -> (Entity, Entity) {
+        (self.data1.rigid_body_entity, self.data2.rigid_body_entity)
+    }
+
+    /// Returns the contact normal of the deepest contact point in world space, pointing from the
+    /// first shape toward the second
+    ///
+    /// See [`ContactPoint::normal`] for the normal at each point.
+    #[must_use]
+    pub fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    /// Returns the contact points
+    ///
+    /// It includes the speculative contacts, where the shapes are close but not touching yet. Their
+    /// penetration depth is negative.
+    #[must_use]
+    pub fn points(&self) -> &[ContactPoint] {
+        &self.points
+    }
+
+    /// Returns the deepest penetration depth among all the contact points
+    #[must_use]
+    pub fn max_penetration_depth(&self) -> f32 {
+        self.points
+            .iter()
+            .map(|point| point.penetration_depth)
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Returns the sum of the impulses applied at each contact point
+    #[must_use]
+    pub fn total_impulse(&self) -> f32 {
+        self.points.iter().map(|point| point.impulse).sum()
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::math::{Vec2, Vec3};
+use bevy::reflect::prelude::*;
 
 /// Resource that defines world's gravity.
 ///
@@ -50,3 +51,30 @@ impl From<Gravity> for Vec3 {
         g.vector()
     }
 }
+
+/// Component that scales the world's [`Gravity`] for a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// A scale of `1.0` (the default) means the body is affected by the gravity normally, `0.0` means
+/// it isn't affected at all, and a negative value makes it fall upward.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(GravityScale(-0.1)); // A balloon, slowly going up
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
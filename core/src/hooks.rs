@@ -0,0 +1,119 @@
+use bevy::math::Vec3;
+use bevy::reflect::prelude::*;
+
+/// Component that enables the user-defined physics hooks for the [`CollisionShape`](crate::CollisionShape)
+/// of the same entity
+///
+/// The physics hooks are defined by implementing the `PhysicsHooks` trait of `heron_rapier`, and
+/// inserting it as a resource. For performance reasons, they are only called for the pairs of
+/// collision shapes where at least one of them enables the hooks with this component.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ActivePhysicsHooks {
+///             filter_contact_pairs: true,
+///             ..Default::default()
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Reflect)]
+pub struct ActivePhysicsHooks {
+    /// Call the hook deciding if the contacts of a pair of collision shapes should be computed
+    pub filter_contact_pairs: bool,
+
+    /// Call the hook modifying the contacts before they are solved
+    pub modify_solver_contacts: bool,
+}
+
+impl ActivePhysicsHooks {
+    /// Enables all the hooks
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            filter_contact_pairs: true,
+            modify_solver_contacts: true,
+        }
+    }
+}
+
+/// Component that makes the [`CollisionShape`](crate::CollisionShape) of the same entity a one-way
+/// platform
+///
+/// Other bodies may pass through the platform when moving in the `allowed_direction`, and are
+/// blocked when coming from the other side. Typically, for a jump-through platform in a 2d
+/// platformer, characters can jump through from below, and then stand on top of the platform.
+///
+/// The `allowed_direction` is defined in the local space of the collision shape, so that it
+/// follows the rotation of the platform.
+///
+/// The decision is made for each pair of collision shapes in contact: a body that started to pass
+/// through the platform is not blocked until it has completely passed through, regardless of the
+/// other bodies touching the platform.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Cuboid {
+///             half_extends: Vec3::new(5.0, 0.5, 0.0),
+///             border_radius: None,
+///         })
+///         .insert(OneWayPlatform::default()); // Bodies can pass through from below
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct OneWayPlatform {
+    /// Direction in which bodies can pass through the platform, in the local space of the
+    /// collision shape
+    ///
+    /// This is also the side of the platform on which the bodies are blocked (and can stand).
+    pub allowed_direction: Vec3,
+}
+
+impl Default for OneWayPlatform {
+    fn default() -> Self {
+        Self {
+            allowed_direction: Vec3::Y,
+        }
+    }
+}
+
+/// Component that defines the velocity of the surface of the [`CollisionShape`](crate::CollisionShape)
+/// of the same entity
+///
+/// The bodies in contact with the collision shape are dragged along by the friction, as if the
+/// surface was moving, without moving the collision shape itself. This is useful for conveyor
+/// belts, escalators, or rivers.
+///
+/// The velocity is defined in the local space of the collision shape, so that it follows the
+/// rotation of the collision shape. Only the part of the velocity that is tangent to the contact
+/// surface has an effect.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Cuboid {
+///             half_extends: Vec3::new(5.0, 0.5, 0.0),
+///             border_radius: None,
+///         })
+///         .insert(SurfaceVelocity(Vec3::X * 2.0)); // Conveyor belt moving to the right
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct SurfaceVelocity(pub Vec3);
@@ -0,0 +1,29 @@
+use bevy::reflect::prelude::*;
+
+/// Component that smooths the rendered movement of a rigid body between two physics steps
+///
+/// When the physics steps are less frequent than the frames (see
+/// [`PhysicsSteps::from_steps_per_seconds`](crate::PhysicsSteps::from_steps_per_seconds)), the
+/// pose of the bodies only changes on the frames where a physics step is performed, which makes
+/// the motion stutter.
+///
+/// With this component, the `Transform` of the body is updated at every frame, by blending the
+/// poses of the last two physics steps according to the time elapsed since the last step. The
+/// rendered pose is therefore slightly behind the simulation, but moves smoothly.
+///
+/// The simulation itself is not affected. Updating the `Transform` still teleports the body.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(TransformInterpolation);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Reflect)]
+pub struct TransformInterpolation;
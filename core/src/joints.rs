@@ -0,0 +1,113 @@
+use bevy::ecs::entity::Entity;
+use bevy::math::{Quat, Vec3};
+
+/// Component that defines a joint between two rigid bodies
+///
+/// The joint can be inserted on any entity (typically a dedicated one). It is created as soon as
+/// both `body1` and `body2` are rigid bodies, and removed when the component is removed or when
+/// one of the two bodies is removed.
+///
+/// The anchors and axes are expressed in the local space of the respective body.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     let frame = commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .id();
+///
+///     let door = commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(1.0, 2.0, 0.1), border_radius: None })
+///         .id();
+///
+///     // Hinge the door on the frame
+///     commands.spawn().insert(Joint::new(
+///         frame,
+///         door,
+///         JointKind::Revolute {
+///             local_anchor1: Vec3::ZERO,
+///             local_axis1: Vec3::Y,
+///             local_anchor2: Vec3::new(-1.0, 0.0, 0.0),
+///             local_axis2: Vec3::Y,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Joint {
+    /// The entity of the first rigid body
+    pub body1: Entity,
+
+    /// The entity of the second rigid body
+    pub body2: Entity,
+
+    /// The kind of joint, and its local anchors/axes
+    pub kind: JointKind,
+}
+
+/// The kind of a [`Joint`], and its anchors and axes in the local space of each body
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JointKind {
+    /// Prevents any relative movement between the two bodies
+    Fixed {
+        /// Position of the anchor in the local space of the first body
+        local_anchor1: Vec3,
+        /// Orientation of the anchor in the local space of the first body
+        local_rotation1: Quat,
+        /// Position of the anchor in the local space of the second body
+        local_anchor2: Vec3,
+        /// Orientation of the anchor in the local space of the second body
+        local_rotation2: Quat,
+    },
+
+    /// Prevents any relative translation between the two anchors, but allows any rotation
+    /// (spherical joint)
+    ///
+    /// In 2d, it is equivalent to a [`JointKind::Revolute`] joint
+    Ball {
+        /// Position of the anchor in the local space of the first body
+        local_anchor1: Vec3,
+        /// Position of the anchor in the local space of the second body
+        local_anchor2: Vec3,
+    },
+
+    /// Prevents any relative translation between the two anchors, and allows rotation around a
+    /// single axis only (hinge)
+    ///
+    /// In 2d, the axes are ignored as the rotation is always around the `z` axis
+    Revolute {
+        /// Position of the anchor in the local space of the first body
+        local_anchor1: Vec3,
+        /// Rotation axis in the local space of the first body
+        local_axis1: Vec3,
+        /// Position of the anchor in the local space of the second body
+        local_anchor2: Vec3,
+        /// Rotation axis in the local space of the second body
+        local_axis2: Vec3,
+    },
+
+    /// Prevents any relative rotation, and allows translation along a single axis only (slider)
+    Prismatic {
+        /// Position of the anchor in the local space of the first body
+        local_anchor1: Vec3,
+        /// Translation axis in the local space of the first body
+        local_axis1: Vec3,
+        /// Position of the anchor in the local space of the second body
+        local_anchor2: Vec3,
+        /// Translation axis in the local space of the second body
+        local_axis2: Vec3,
+    },
+}
+
+impl Joint {
+    /// Create a new joint of the given kind between two rigid body entities
+    #[must_use]
+    pub fn new(body1: Entity, body2: Entity, kind: JointKind) -> Self {
+        Self { body1, body2, kind }
+    }
+}
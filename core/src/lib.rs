@@ -7,20 +7,40 @@
 use bevy::ecs::schedule::ShouldRun;
 use bevy::prelude::*;
 
+pub use character::{CharacterController, CharacterControllerOutput, CharacterHit};
+#[cfg(feature = "collision-from-mesh")]
+pub use collision_from_mesh::{ColliderFromMesh, MeshColliderKind};
 pub use constraints::RotationConstraints;
-pub use events::{CollisionData, CollisionEvent};
-pub use gravity::Gravity;
+pub use damping::Damping;
+pub use dominance::Dominance;
+pub use events::{CollisionData, CollisionEvent, ContactDetails, ContactPoint};
+pub use gravity::{Gravity, GravityScale};
+pub use hooks::{ActivePhysicsHooks, OneWayPlatform, SurfaceVelocity};
+pub use interpolation::TransformInterpolation;
+pub use joints::{Joint, JointKind};
 pub use layers::{CollisionLayers, PhysicsLayer};
+pub use mass_properties::MassProperties;
 pub use physics_time::PhysicsTime;
+pub use sleeping::{BodySleep, BodyWake, SleepThreshold, Sleeping};
 pub use step::{PhysicsStepDuration, PhysicsSteps};
-pub use velocity::{Acceleration, AxisAngle, Velocity};
+pub use velocity::{Acceleration, AxisAngle, ExternalForce, ForceSpace, Impulse, Velocity};
 
+mod character;
+#[cfg(feature = "collision-from-mesh")]
+mod collision_from_mesh;
 mod constraints;
+mod damping;
+mod dominance;
 mod events;
 pub mod ext;
 mod gravity;
+mod hooks;
+mod interpolation;
+mod joints;
 mod layers;
+mod mass_properties;
 mod physics_time;
+mod sleeping;
 mod step;
 pub mod utils;
 mod velocity;
@@ -64,17 +84,37 @@ impl Plugin for CorePlugin {
             .register_type::<CollisionShape>()
             .register_type::<RigidBody>()
             .register_type::<PhysicMaterial>()
+            .register_type::<MassProperties>()
             .register_type::<Velocity>()
             .register_type::<Acceleration>()
+            .register_type::<ExternalForce>()
+            .register_type::<Impulse>()
+            .register_type::<Damping>()
+            .register_type::<GravityScale>()
+            .register_type::<Dominance>()
             .register_type::<RotationConstraints>()
             .register_type::<CollisionLayers>()
             .register_type::<SensorShape>()
+            .register_type::<ActivePhysicsHooks>()
+            .register_type::<OneWayPlatform>()
+            .register_type::<SurfaceVelocity>()
+            .register_type::<ContinuousCollisionDetection>()
+            .register_type::<Sleeping>()
+            .register_type::<SleepThreshold>()
+            .register_type::<TransformInterpolation>()
+            .register_type::<CharacterController>()
             .add_system_to_stage(CoreStage::First, PhysicsSteps::update.system())
             .add_stage_before(CoreStage::PostUpdate, crate::stage::ROOT, {
                 Schedule::default()
                     .with_run_criteria(should_run.system())
                     .with_stage(crate::stage::UPDATE, SystemStage::parallel())
             });
+
+        #[cfg(feature = "collision-from-mesh")]
+        app.add_system_to_stage(
+            CoreStage::PreUpdate,
+            collision_from_mesh::insert_collision_shapes.system(),
+        );
     }
 }
 
@@ -91,6 +131,24 @@ pub fn should_run(
     }
 }
 
+/// Run criteria system that decides if the physics step systems should run (again)
+///
+/// The physics step systems run once for each of the steps to perform in the frame. (see
+/// [`PhysicsSteps::from_fixed_timestep`])
+#[must_use]
+pub fn should_step(
+    mut physics_steps: ResMut<'_, PhysicsSteps>,
+    physics_time: Res<'_, PhysicsTime>,
+) -> ShouldRun {
+    if physics_time.scale() <= 0.0 || !physics_steps.take_step() {
+        ShouldRun::No
+    } else if physics_steps.has_remaining_steps() {
+        ShouldRun::YesAndCheckAgain
+    } else {
+        ShouldRun::Yes
+    }
+}
+
 /// Components that defines the collision shape of a rigid body
 ///
 /// The collision shape will be attached to the [`RigidBody`] of the same entity.
@@ -163,6 +221,119 @@ pub enum CollisionShape {
         /// inner `Vec`, any other element will be ignored.
         heights: Vec<Vec<f32>>,
     },
+
+    /// A triangle mesh shape, defined by its vertices and the indices of its triangles
+    ///
+    /// A triangle mesh is hollow and can be concave. It is well suited for static geometry such as
+    /// levels, but should not be used for dynamic bodies.
+    ///
+    /// In 2d, the `z` coordinate of the vertices is ignored.
+    TriMesh {
+        /// The vertices of the mesh
+        vertices: Vec<Vec3>,
+
+        /// The indices of the 3 vertices of each triangle
+        indices: Vec<[u32; 3]>,
+    },
+
+    /// A polyline shape, made of segments between vertices
+    ///
+    /// It is well suited for outlines of 2d terrains.
+    ///
+    /// In 2d, the `z` coordinate of the vertices is ignored.
+    Polyline {
+        /// The vertices of the polyline
+        vertices: Vec<Vec3>,
+
+        /// The indices of the 2 vertices of each segment
+        ///
+        /// If `None`, the vertices are connected in order (without closing the loop)
+        indices: Option<Vec<[u32; 2]>>,
+    },
+
+    /// A shape made of several other shapes, each with its own position and rotation relative to
+    /// the entity
+    ///
+    /// It allows defining many shapes for a single rigid body without spawning child entities.
+    ///
+    /// The scale of the transforms is ignored. The inner [`CollisionShape::Compound`] and
+    /// [`CollisionShape::ConvexDecomposition`] are flattened into the compound. The inner
+    /// [`CollisionShape::TriMesh`], [`CollisionShape::Polyline`] and [`CollisionShape::HeightField`]
+    /// are not supported, and are ignored with a warning.
+    ///
+    /// If the compound has no supported inner shape, it is replaced by a point (a sphere of radius
+    /// zero).
+    Compound(Vec<(Transform, CollisionShape)>),
+
+    /// A concave shape, approximated by a compound of convex hulls
+    ///
+    /// Unlike [`CollisionShape::TriMesh`], it is solid and can be used for dynamic bodies, which
+    /// makes it well suited for concave props such as chairs or tables.
+    ///
+    /// The decomposition is computed (with the V-HACD algorithm) when the collider is created,
+    /// which may take some time for big meshes.
+    ///
+    /// In 2d, the `z` coordinate of the vertices is ignored, and only the outline of the
+    /// triangles is taken into account.
+    ConvexDecomposition {
+        /// The vertices of the mesh
+        vertices: Vec<Vec3>,
+
+        /// The indices of the 3 vertices of each triangle
+        indices: Vec<[u32; 3]>,
+
+        /// The parameters of the decomposition
+        parameters: ConvexDecompositionParameters,
+    },
+
+    /// A segment shape, between two points
+    Segment {
+        /// The first point of the segment
+        a: Vec3,
+        /// The second point of the segment
+        b: Vec3,
+    },
+
+    /// A triangle shape
+    Triangle {
+        /// The first point of the triangle
+        a: Vec3,
+        /// The second point of the triangle
+        b: Vec3,
+        /// The third point of the triangle
+        c: Vec3,
+    },
+
+    /// An infinite half-space (or half-plane in 2d), delimited by a plane passing through the
+    /// origin of the entity
+    ///
+    /// This is useful for infinite grounds.
+    HalfSpace {
+        /// The normal of the plane, pointing toward the outside of the half-space
+        outward_normal: Vec3,
+    },
+
+    /// A cylinder shape, aligned on the `y` axis
+    ///
+    /// Only available in 3d
+    #[cfg(feature = "3d")]
+    Cylinder {
+        /// Distance from the center of the cylinder to its top/bottom faces
+        half_height: f32,
+        /// Radius of the cylinder
+        radius: f32,
+    },
+
+    /// A cone shape, aligned on the `y` axis with its tip pointing toward positive `y`
+    ///
+    /// Only available in 3d
+    #[cfg(feature = "3d")]
+    Cone {
+        /// Distance from the center of the cone to its tip (and to its base)
+        half_height: f32,
+        /// Radius of the base of the cone
+        radius: f32,
+    },
 }
 
 impl Default for CollisionShape {
@@ -171,6 +342,39 @@ impl Default for CollisionShape {
     }
 }
 
+/// Parameters of the approximate convex decomposition of a
+/// [`CollisionShape::ConvexDecomposition`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ConvexDecompositionParameters {
+    /// Resolution of the voxelization of the shape
+    ///
+    /// Higher values give more accurate results, at the cost of a longer decomposition.
+    pub resolution: u32,
+
+    /// Maximum concavity allowed in each convex part
+    ///
+    /// Lower values produce more (and smaller) convex parts that better fit the shape.
+    pub concavity: f32,
+}
+
+impl Default for ConvexDecompositionParameters {
+    #[cfg(not(feature = "3d"))]
+    fn default() -> Self {
+        Self {
+            resolution: 256,
+            concavity: 0.1,
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    fn default() -> Self {
+        Self {
+            resolution: 64,
+            concavity: 0.01,
+        }
+    }
+}
+
 /// Component that mark the entity as being a rigid body
 ///
 /// It'll need some [`CollisionShape`] to be attached. Either in the same entity or in a direct child
@@ -281,10 +485,37 @@ impl RigidBody {
 #[derive(Debug, Copy, Clone, Default, Reflect)]
 pub struct SensorShape;
 
+/// Enable continuous collision detection (CCD) for the [`RigidBody`] of the same entity
+///
+/// Without CCD, fast and small bodies (such as bullets) may pass through thin obstacles, because
+/// they can be on one side before the step, and on the other side after the step.
+///
+/// CCD prevents that, at the cost of some performance. So it should only be enabled for the bodies
+/// that need it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 0.1 })
+///         .insert(Velocity::from_linear(Vec3::X * 1000.0))
+///         .insert(ContinuousCollisionDetection); // Prevent the bullet from tunneling
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Default, Reflect)]
+pub struct ContinuousCollisionDetection;
+
 /// Component that defines the physics properties of the rigid body
 ///
 /// It must be inserted on the same entity of a [`RigidBody`]
 ///
+/// It may also be inserted on a child entity holding a [`CollisionShape`], in which case it
+/// overrides the material of the rigid body for that collision shape only.
+///
 /// # Example
 ///
 /// ```
@@ -297,6 +528,7 @@ pub struct SensorShape;
 ///             restitution: 0.5, // Define the restitution. Higher value means more "bouncy"
 ///             density: 2.0, // Define the density. Higher value means heavier.
 ///             friction: 0.5, // Define the friction. Higher value means higher friction.
+///             ..Default::default()
 ///         });
 /// }
 /// ```
@@ -322,6 +554,46 @@ pub struct PhysicMaterial {
     ///
     /// Typical values are between 0 (ideal) and 1 (max friction)
     pub friction: f32,
+
+    /// How the friction is combined with the one of the other collider, when in contact
+    ///
+    /// If the two colliders have a different rule, the one with the highest precedence is used
+    /// (in order: [`CombineRule::Max`], [`CombineRule::Multiply`], [`CombineRule::Min`], and
+    /// [`CombineRule::Average`]).
+    pub friction_combine_rule: CombineRule,
+
+    /// How the restitution is combined with the one of the other collider, when in contact
+    ///
+    /// If the two colliders have a different rule, the one with the highest precedence is used
+    /// (in order: [`CombineRule::Max`], [`CombineRule::Multiply`], [`CombineRule::Min`], and
+    /// [`CombineRule::Average`]).
+    pub restitution_combine_rule: CombineRule,
+}
+
+/// Rule used to combine the friction or restitution coefficients of two colliders in contact
+///
+/// See [`PhysicMaterial::friction_combine_rule`] and [`PhysicMaterial::restitution_combine_rule`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum CombineRule {
+    /// Use the average of the two coefficients
+    ///
+    /// This is the default.
+    Average,
+
+    /// Use the smallest of the two coefficients
+    Min,
+
+    /// Use the product of the two coefficients
+    Multiply,
+
+    /// Use the greatest of the two coefficients
+    Max,
+}
+
+impl Default for CombineRule {
+    fn default() -> Self {
+        Self::Average
+    }
 }
 
 impl PhysicMaterial {
@@ -338,6 +610,8 @@ impl Default for PhysicMaterial {
             restitution: Self::PERFECTLY_INELASTIC_RESTITUTION,
             density: 1.0,
             friction: 0.0,
+            friction_combine_rule: CombineRule::default(),
+            restitution_combine_rule: CombineRule::default(),
         }
     }
 }
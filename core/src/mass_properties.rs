@@ -0,0 +1,59 @@
+use bevy::math::prelude::*;
+use bevy::reflect::prelude::*;
+
+/// Component that explicitly defines the mass properties of a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// By default, the mass properties are computed from the [`CollisionShape`](crate::CollisionShape)s
+/// and their [`PhysicMaterial::density`](crate::PhysicMaterial::density). When this component is
+/// present, the densities of all the collision shapes of the body (including the ones of its
+/// children, and their own [`PhysicMaterial`](crate::PhysicMaterial)) are ignored, and the body uses
+/// exactly the given mass properties.
+///
+/// This component is only an input: it is not inserted nor updated by the physics plugin, and
+/// it doesn't reflect the mass properties computed from the densities when it is absent.
+/// Inserting, changing or removing it re-creates the rigid body.
+///
+/// This is useful for vehicles that need a low center of mass, or for characters whose mass
+/// is tuned for gameplay.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(2.0, 1.0, 1.0), border_radius: None })
+///         .insert(MassProperties {
+///             mass: 1500.0,
+///             local_center_of_mass: Vec3::new(0.0, -0.5, 0.0), // Lower center of mass
+///             principal_inertia: Vec3::new(500.0, 2000.0, 2000.0),
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct MassProperties {
+    /// Total mass of the body
+    pub mass: f32,
+
+    /// Center of mass, relative to the position of the body
+    pub local_center_of_mass: Vec3,
+
+    /// Principal angular inertia around each axis
+    ///
+    /// In 2d only the `z` component is used (rotations around the `z` axis).
+    pub principal_inertia: Vec3,
+}
+
+impl Default for MassProperties {
+    fn default() -> Self {
+        Self {
+            mass: 1.0,
+            local_center_of_mass: Vec3::ZERO,
+            principal_inertia: Vec3::ONE,
+        }
+    }
+}
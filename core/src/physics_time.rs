@@ -1,5 +1,8 @@
 /// Resource that controls the physics time scale
 ///
+/// The scale applies to the whole simulation, including the movement of the
+/// [`RigidBody::KinematicVelocityBased`](crate::RigidBody) bodies by their [`Velocity`](crate::Velocity).
+///
 /// # Example
 ///
 /// ```
@@ -0,0 +1,73 @@
+use bevy::ecs::entity::Entity;
+use bevy::reflect::prelude::*;
+
+/// Component marking a rigid body as sleeping
+///
+/// Bodies that stop moving are put to sleep by the physics engine, so that they no longer consume
+/// resources for the simulation, until something (like a collision) wakes them up.
+///
+/// This component is kept in sync with the physics world: it is inserted when the body falls
+/// asleep and removed when it wakes up.
+///
+/// It may also be inserted to force a body to sleep, and removed to wake it up.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn detect_settled_bodies(bodies: Query<Entity, (With<RigidBody>, Added<Sleeping>)>) {
+///     for entity in bodies.iter() {
+///         println!("{:?} has settled", entity);
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Default, Reflect)]
+pub struct Sleeping;
+
+/// Component that defines how still a rigid body must be, before it can fall asleep
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// The value is an energy threshold (proportional to the squared velocity of the body), below which
+/// the body falls asleep. A negative value prevents the body from sleeping.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(SleepThreshold::NEVER); // This body is never put to sleep
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct SleepThreshold(pub f32);
+
+impl SleepThreshold {
+    /// Threshold that prevents the body from sleeping
+    pub const NEVER: Self = Self(-1.0);
+}
+
+impl Default for SleepThreshold {
+    /// Returns the default threshold of rapier (`RigidBodyActivation::default_threshold()`), which
+    /// is also used by the bodies without this component
+    fn default() -> Self {
+        Self(0.01)
+    }
+}
+
+/// An event fired when a rigid body falls asleep
+///
+/// See [`Sleeping`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BodySleep(pub Entity);
+
+/// An event fired when a rigid body wakes up
+///
+/// See [`Sleeping`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BodyWake(pub Entity);
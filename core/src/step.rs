@@ -4,18 +4,30 @@ use bevy::prelude::*;
 
 /// Resource to control how many physics steps are performed per second.
 ///
-/// Note that the physics update will be performed at most once per frame. It means that if the rate of
-/// frames per second is lower than the physics step per second, the physics simulation will slows down.
+/// Note that, except with [`PhysicsSteps::from_fixed_timestep`], the physics update will be performed
+/// at most once per frame. It means that if the rate of frames per second is lower than the physics
+/// step per second, the physics simulation will slows down.
 ///
 /// This resource is used to tune the precision and performance of the physics system.
 /// It doesn't change the speed of the simulation.
 /// To change the time scale, look at the [`PhysicsTime`](crate::PhysicsTime) resource instead.
-pub struct PhysicsSteps(Mode);
+pub struct PhysicsSteps {
+    mode: Mode,
+    substeps: u32,
+    steps_in_frame: u32,
+    remaining_steps: u32,
+}
 
 enum Mode {
     MaxDeltaTime(Duration),
     EveryFrame(Duration),
     Timer(Timer),
+    FixedTimestep {
+        step: Duration,
+        max_steps_per_frame: u32,
+        accumulated: Duration,
+    },
+    Manual(Duration),
 }
 
 impl Default for PhysicsSteps {
@@ -46,6 +58,15 @@ impl PhysicsStepDuration {
 }
 
 impl PhysicsSteps {
+    fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            substeps: 1,
+            steps_in_frame: 0,
+            remaining_steps: 0,
+        }
+    }
+
     /// Configure to run at the given number of steps per second
     ///
     /// The higher the value, the more precise and the more expensive the physics simulation will be.
@@ -63,7 +84,7 @@ impl PhysicsSteps {
             "Invalid steps per second: {}",
             steps_per_second
         );
-        Self(Mode::Timer(Timer::from_seconds(
+        Self::new(Mode::Timer(Timer::from_seconds(
             1.0 / steps_per_second,
             true,
         )))
@@ -82,7 +103,7 @@ impl PhysicsSteps {
     #[must_use]
     pub fn from_delta_time(duration: Duration) -> Self {
         assert_ne!(!duration.as_nanos(), 0, "Invalid duration: {:?}", duration);
-        Self(Mode::Timer(Timer::new(duration, true)))
+        Self::new(Mode::Timer(Timer::new(duration, true)))
     }
 
     /// Configure the physics systems to run at each and every frame, advancing the simulation the
@@ -96,7 +117,7 @@ impl PhysicsSteps {
     #[must_use]
     pub fn every_frame(duration: Duration) -> Self {
         assert_ne!(!duration.as_micros(), 0, "Invalid duration: {:?}", duration);
-        Self(Mode::EveryFrame(duration))
+        Self::new(Mode::EveryFrame(duration))
     }
 
     /// Step the physics simulation every frame, advancing the simulation according to the frame
@@ -130,23 +151,141 @@ impl PhysicsSteps {
     /// ```
     #[must_use]
     pub fn from_max_delta_time(max: Duration) -> Self {
-        Self(Mode::MaxDeltaTime(max))
+        Self::new(Mode::MaxDeltaTime(max))
+    }
+
+    /// Configure the physics systems to run as many steps of the given duration as needed to keep
+    /// up with the elapsed time, but at most `max_steps_per_frame` steps per frame
+    ///
+    /// The elapsed time is accumulated, and consumed by steps of the given fixed duration. When
+    /// several step durations elapsed since the last frame (for instance after a frame spike), the
+    /// physics systems run several times in the same frame to catch up, so that the simulation
+    /// time doesn't drift away from the real time.
+    ///
+    /// The maximum number of steps per frame prevents a "spiral of death", where the steps take so
+    /// long that more and more steps are needed. If more steps are needed, the excess time is
+    /// dropped, and the simulation slows down.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use heron_core::PhysicsSteps;
+    /// # use std::time::Duration;
+    /// App::build()
+    ///     // Runs 60 physics steps per second, with at most 4 steps per frame
+    ///     .insert_resource(PhysicsSteps::from_fixed_timestep(Duration::from_secs_f64(1.0 / 60.0), 4))
+    ///     // ...
+    ///     .run();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration or the maximum number of steps per frame is zero
+    #[must_use]
+    pub fn from_fixed_timestep(step: Duration, max_steps_per_frame: u32) -> Self {
+        assert_ne!(step.as_nanos(), 0, "Invalid duration: {step:?}");
+        assert_ne!(
+            max_steps_per_frame, 0,
+            "Invalid maximum number of steps per frame"
+        );
+        Self::new(Mode::FixedTimestep {
+            step,
+            max_steps_per_frame,
+            accumulated: Duration::default(),
+        })
+    }
+
+    /// Never step the physics simulation automatically
+    ///
+    /// The physics steps are only performed on demand, with the `PhysicsStepper` of `heron_rapier`,
+    /// each step advancing the simulation by the given duration. This is meant for games that need
+    /// full control over the simulation, like rollback netcode, which re-runs several steps in a
+    /// single frame.
+    ///
+    /// In this mode, the physics stages (including the deprecated [`stage::UPDATE`](crate::stage))
+    /// never run, as there is no frame with a physics step. The systems updating the physics
+    /// components must run before the steps are performed instead, for instance in the bevy update
+    /// stage.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use heron_core::PhysicsSteps;
+    /// # use std::time::Duration;
+    /// App::build()
+    ///     // The physics steps are performed by the game, each one advancing the simulation by 1/60 s
+    ///     .insert_resource(PhysicsSteps::manual(Duration::from_secs_f64(1.0 / 60.0)))
+    ///     // ...
+    ///     .run();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero
+    #[must_use]
+    pub fn manual(step: Duration) -> Self {
+        assert_ne!(step.as_nanos(), 0, "Invalid duration: {step:?}");
+        Self::new(Mode::Manual(step))
+    }
+
+    /// Split each physics step into the given number of substeps
+    ///
+    /// Each substep advances the simulation by a fraction of the step duration. This improves the
+    /// precision of the simulation (stiffer contacts and joints, less tunneling), at the cost of
+    /// performance. The collision events and the components are updated only once per step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of substeps is zero
+    #[must_use]
+    pub fn with_substeps(mut self, substeps: u32) -> Self {
+        assert_ne!(substeps, 0, "Invalid number of substeps");
+        self.substeps = substeps;
+        self
+    }
+
+    /// Number of substeps performed for each physics step
+    #[must_use]
+    pub fn substeps(&self) -> u32 {
+        self.substeps
+    }
+
+    /// Number of physics steps to perform in the current frame
+    #[must_use]
+    pub fn steps_in_frame(&self) -> u32 {
+        self.steps_in_frame
     }
 
     /// Returns true only if the current frame is a frame that execute a physics simulation step
     #[must_use]
     pub fn is_step_frame(&self) -> bool {
-        match &self.0 {
-            Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) => true,
-            Mode::Timer(timer) => timer.just_finished(),
+        self.steps_in_frame > 0
+    }
+
+    /// Fraction of the time between two physics steps that elapsed since the last step
+    ///
+    /// The value is between `0.0` (a step was just performed) and `1.0` (the next step is due). It
+    /// is always `1.0` if the physics steps are performed at every frame.
+    #[must_use]
+    pub fn interpolation_fraction(&self) -> f32 {
+        match &self.mode {
+            Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) | Mode::Manual(_) => 1.0,
+            Mode::Timer(timer) => timer.percent(),
+            Mode::FixedTimestep {
+                step, accumulated, ..
+            } => accumulated.as_secs_f32() / step.as_secs_f32(),
         }
     }
 
     /// Time that elapses between each physics step
     #[must_use]
     pub fn duration(&self) -> PhysicsStepDuration {
-        match &self.0 {
-            Mode::EveryFrame(duration) => PhysicsStepDuration::Exact(*duration),
+        match &self.mode {
+            Mode::EveryFrame(duration)
+            | Mode::FixedTimestep { step: duration, .. }
+            | Mode::Manual(duration) => PhysicsStepDuration::Exact(*duration),
             Mode::Timer(timer) => PhysicsStepDuration::Exact(timer.duration()),
             Mode::MaxDeltaTime(max) => PhysicsStepDuration::MaxDeltaTime(*max),
         }
@@ -156,11 +295,52 @@ impl PhysicsSteps {
         physics_steps.do_update(time.delta());
     }
 
+    /// Consume one of the steps to perform in the current frame
+    ///
+    /// Returns `false` if all the steps of the frame have already been performed
+    pub(crate) fn take_step(&mut self) -> bool {
+        if self.remaining_steps == 0 {
+            false
+        } else {
+            self.remaining_steps -= 1;
+            true
+        }
+    }
+
+    /// Returns true if there are more steps to perform in the current frame
+    pub(crate) fn has_remaining_steps(&self) -> bool {
+        self.remaining_steps > 0
+    }
+
     #[inline]
     fn do_update(&mut self, delta: Duration) {
-        if let Mode::Timer(timer) = &mut self.0 {
-            timer.tick(delta);
-        }
+        self.steps_in_frame = match &mut self.mode {
+            Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) => 1,
+            Mode::Timer(timer) => {
+                timer.tick(delta);
+                u32::from(timer.just_finished())
+            }
+            Mode::FixedTimestep {
+                step,
+                max_steps_per_frame,
+                accumulated,
+            } => {
+                *accumulated += delta;
+                let mut steps = 0;
+                while *accumulated >= *step && steps < *max_steps_per_frame {
+                    *accumulated -= *step;
+                    steps += 1;
+                }
+                if *accumulated >= *step {
+                    // Drop the time that cannot be simulated, to avoid a spiral of death
+                    *accumulated =
+                        Duration::from_secs_f64(accumulated.as_secs_f64() % step.as_secs_f64());
+                }
+                steps
+            }
+            Mode::Manual(_) => 0,
+        };
+        self.remaining_steps = self.steps_in_frame;
     }
 }
 
@@ -174,6 +354,7 @@ mod tests {
     #[case(PhysicsSteps::from_delta_time(Duration::from_secs(1)), 0.9)]
     #[case(PhysicsSteps::from_delta_time(Duration::from_secs_f32(0.016)), 0.01)]
     #[case(PhysicsSteps::from_steps_per_seconds(10.0), 0.09)]
+    #[case(PhysicsSteps::manual(Duration::from_secs(1)), 10.0)]
     fn is_not_step_frame_if_not_enough_time_has_elapsed(
         #[case] mut steps: PhysicsSteps,
         #[case] delta_time: f32,
@@ -195,4 +376,68 @@ mod tests {
         steps.do_update(Duration::from_secs_f32(delta_time));
         assert!(steps.is_step_frame())
     }
+
+    #[rstest]
+    #[case(PhysicsSteps::from_delta_time(Duration::from_secs(1)), 0.25, 0.25)]
+    #[case(PhysicsSteps::from_delta_time(Duration::from_secs(1)), 1.5, 0.5)]
+    #[case(PhysicsSteps::from_steps_per_seconds(10.0), 0.05, 0.5)]
+    #[case(PhysicsSteps::every_frame(Duration::from_secs(1)), 0.5, 1.0)]
+    #[case(PhysicsSteps::from_max_delta_time(Duration::from_secs(1)), 0.5, 1.0)]
+    fn interpolation_fraction(
+        #[case] mut steps: PhysicsSteps,
+        #[case] delta_time: f32,
+        #[case] expected: f32,
+    ) {
+        steps.do_update(Duration::from_secs_f32(delta_time));
+        assert!((steps.interpolation_fraction() - expected).abs() < 0.001);
+    }
+
+    #[rstest]
+    #[case(0.5, 0, 0.5)]
+    #[case(1.0, 1, 0.0)]
+    #[case(2.5, 2, 0.5)]
+    #[case(3.0, 3, 0.0)]
+    #[case(10.25, 3, 0.25)]
+    fn fixed_timestep_catches_up_to_max_steps(
+        #[case] delta_time: f32,
+        #[case] expected_steps: u32,
+        #[case] expected_fraction: f32,
+    ) {
+        let mut steps = PhysicsSteps::from_fixed_timestep(Duration::from_secs(1), 3);
+        steps.do_update(Duration::from_secs_f32(delta_time));
+
+        assert_eq!(steps.steps_in_frame(), expected_steps);
+        assert_eq!(steps.is_step_frame(), expected_steps > 0);
+        assert!((steps.interpolation_fraction() - expected_fraction).abs() < 0.001);
+    }
+
+    #[test]
+    fn fixed_timestep_accumulates_time_across_frames() {
+        let mut steps = PhysicsSteps::from_fixed_timestep(Duration::from_secs(1), 3);
+
+        steps.do_update(Duration::from_secs_f32(0.6));
+        assert!(!steps.is_step_frame());
+
+        steps.do_update(Duration::from_secs_f32(0.6));
+        assert_eq!(steps.steps_in_frame(), 1);
+    }
+
+    #[test]
+    fn steps_are_taken_once() {
+        let mut steps = PhysicsSteps::from_fixed_timestep(Duration::from_secs(1), 3);
+        steps.do_update(Duration::from_secs(2));
+
+        assert!(steps.take_step());
+        assert!(steps.has_remaining_steps());
+        assert!(steps.take_step());
+        assert!(!steps.has_remaining_steps());
+        assert!(!steps.take_step());
+        assert_eq!(steps.steps_in_frame(), 2);
+    }
+
+    #[test]
+    fn substeps() {
+        assert_eq!(PhysicsSteps::default().substeps(), 1);
+        assert_eq!(PhysicsSteps::default().with_substeps(4).substeps(), 4);
+    }
 }
@@ -70,6 +70,100 @@ pub struct Acceleration {
     pub angular: AxisAngle,
 }
 
+/// Component that defines a force (and torque) continuously applied to a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// Unlike [`Acceleration`], the force is not scaled by the mass of the body, and it can be applied
+/// at an offset from the center of mass, which also makes the body rotate. This is useful for
+/// thrusters, wheels or ropes.
+///
+/// The force, the torque and the point are expressed in the given [`ForceSpace`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ExternalForce {
+///             force: Vec3::Y * 100.0, // Thruster pushing "up", relative to the body
+///             point: Vec3::new(-1.0, 0.0, 0.0), // Thruster on the left side of the body
+///             space: ForceSpace::Local,
+///             ..Default::default()
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct ExternalForce {
+    /// Force applied to the body
+    pub force: Vec3,
+
+    /// Torque applied to the body
+    pub torque: AxisAngle,
+
+    /// Point at which the force is applied
+    pub point: Vec3,
+
+    /// Space in which the force, torque and point are expressed
+    pub space: ForceSpace,
+}
+
+/// Space in which an [`ExternalForce`] is expressed
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum ForceSpace {
+    /// Relative to the position and rotation of the body
+    ///
+    /// A local force turns with the body, and a point at the origin is the position of the body.
+    Local,
+
+    /// In world space
+    World,
+}
+
+impl Default for ForceSpace {
+    fn default() -> Self {
+        Self::Local
+    }
+}
+
+/// Component that applies an instantaneous impulse to a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// Unlike [`Acceleration`], which is applied continuously, the impulse is applied only once, and
+/// the component is then automatically removed. This is useful for jumps, knockbacks or
+/// explosions.
+///
+/// The linear part is in "unit" times mass per second on each axis.
+/// The angular part is an angular impulse around an axis, represented as an [`AxisAngle`]
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn jump(mut commands: Commands, player: Entity) {
+///     commands.entity(player).insert(Impulse::from_linear(Vec3::Y * 10.0));
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct Impulse {
+    /// Linear impulse on each axis
+    pub linear: Vec3,
+
+    /// Angular impulse around an axis
+    pub angular: AxisAngle,
+
+    /// Point, in world-space, at which the linear impulse is applied
+    ///
+    /// If `None`, the linear impulse is applied at the center of mass (and doesn't cause any
+    /// rotation).
+    pub point: Option<Vec3>,
+}
+
 /// An [axis-angle] representation
 ///
 /// [axis-angle]: https://en.wikipedia.org/wiki/Axis%E2%80%93angle_representation
@@ -144,10 +238,52 @@ impl Acceleration {
     }
 }
 
+impl Impulse {
+    /// Returns a linear impulse from a vector
+    #[must_use]
+    pub fn from_linear(linear: Vec3) -> Self {
+        Self {
+            linear,
+            ..Self::default()
+        }
+    }
+
+    /// Returns an angular impulse from a vector
+    #[must_use]
+    pub fn from_angular(angular: AxisAngle) -> Self {
+        Self {
+            angular,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version with the given linear impulse
+    #[must_use]
+    pub fn with_linear(mut self, linear: Vec3) -> Self {
+        self.linear = linear;
+        self
+    }
+
+    /// Returns a new version with the given angular impulse
+    #[must_use]
+    pub fn with_angular(mut self, angular: AxisAngle) -> Self {
+        self.angular = angular;
+        self
+    }
+
+    /// Returns a new version where the linear impulse is applied at the given world-space point
+    #[must_use]
+    pub fn at_point(mut self, point: Vec3) -> Self {
+        self.point = Some(point);
+        self
+    }
+}
+
 #[duplicate(
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Vec2> for Velocity {
     fn from(v: Vec2) -> Self {
@@ -159,6 +295,7 @@ impl From<Vec2> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Vec3> for Velocity {
     fn from(linear: Vec3) -> Self {
@@ -170,6 +307,7 @@ impl From<Vec3> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Velocity> for Vec3 {
     fn from(Velocity { linear, .. }: Velocity) -> Self {
@@ -181,6 +319,7 @@ impl From<Velocity> for Vec3 {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<AxisAngle> for Velocity {
     fn from(angular: AxisAngle) -> Self {
@@ -192,6 +331,7 @@ impl From<AxisAngle> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Quat> for Velocity {
     fn from(quat: Quat) -> Self {
@@ -203,6 +343,7 @@ impl From<Quat> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Velocity> for AxisAngle {
     fn from(Velocity { angular, .. }: Velocity) -> Self {
@@ -214,6 +355,7 @@ impl From<Velocity> for AxisAngle {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl From<Velocity> for Quat {
     fn from(Velocity { angular, .. }: Velocity) -> Self {
@@ -243,6 +385,7 @@ impl From<AxisAngle> for f32 {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Impulse ];
 )]
 impl NearZero for Velocity {
     fn is_near_zero(self) -> bool {
@@ -11,6 +11,9 @@ use heron_rapier::rapier2d::geometry::{ColliderHandle, ColliderSet, Shape};
 
 use super::*;
 
+const SEGMENT_HALF_WIDTH: f32 = 1.0;
+const HALF_SPACE_EXTENT: f32 = 10_000.0;
+
 pub(crate) fn systems() -> SystemSet {
     SystemSet::new()
         .with_system(delete_debug_sprite.system())
@@ -33,14 +36,14 @@ fn create_debug_sprites(
             commands
                 .entity(entity)
                 .with_children(|builder| {
-                    builder
-                        .spawn_bundle(create_shape(
-                            body,
-                            collider.shape(),
-                            (*debug_color).into(),
-                            *transform,
-                        ))
-                        .insert(IsDebug(entity));
+                    spawn_debug_shape(
+                        builder,
+                        entity,
+                        body,
+                        collider.shape(),
+                        (*debug_color).into(),
+                        *transform,
+                    );
                 })
                 .insert(HasDebug);
         }
@@ -62,16 +65,16 @@ fn replace_debug_sprite(
         if let (Some(debug_entity), Some(collider)) =
             (map.remove(&parent_entity), colliders.get(*handle))
         {
-            commands.entity(debug_entity).despawn();
+            commands.entity(debug_entity).despawn_recursive();
             commands.entity(parent_entity).with_children(|builder| {
-                builder
-                    .spawn_bundle(create_shape(
-                        body,
-                        collider.shape(),
-                        (*debug_color).into(),
-                        *transform,
-                    ))
-                    .insert(IsDebug(parent_entity));
+                spawn_debug_shape(
+                    builder,
+                    parent_entity,
+                    body,
+                    collider.shape(),
+                    (*debug_color).into(),
+                    *transform,
+                );
             });
         }
     }
@@ -84,25 +87,61 @@ fn delete_debug_sprite(
 ) {
     for parent_entity in removed_bodies.iter() {
         if let Some(debug_entity) = map.remove(&parent_entity) {
-            commands.entity(debug_entity).despawn();
+            commands.entity(debug_entity).despawn_recursive();
         }
     }
 }
 
-fn create_shape(
+fn spawn_debug_shape(
+    builder: &mut ChildBuilder<'_, '_>,
+    entity: Entity,
     body: &CollisionShape,
     shape: &dyn Shape,
     color: Color,
     transform: GlobalTransform,
+) {
+    let debug_transform = Transform {
+        translation: Vec3::Z,
+        scale: transform.scale.recip(),
+        ..Default::default()
+    };
+
+    if let (CollisionShape::Compound(bodies), Some(compound)) = (body, shape.as_compound()) {
+        // Each inner shape is rendered in its own child of the debug entity, at its own position
+        builder
+            .spawn_bundle((debug_transform, GlobalTransform::default()))
+            .insert(IsDebug(entity))
+            .with_children(|children| {
+                for ((local, body), (_, shape)) in bodies.iter().zip(compound.shapes()) {
+                    children.spawn_bundle(create_shape(
+                        body,
+                        &**shape,
+                        color,
+                        Transform {
+                            translation: local.translation,
+                            rotation: local.rotation,
+                            ..Default::default()
+                        },
+                    ));
+                }
+            });
+    } else {
+        builder
+            .spawn_bundle(create_shape(body, shape, color, debug_transform))
+            .insert(IsDebug(entity));
+    }
+}
+
+fn create_shape(
+    body: &CollisionShape,
+    shape: &dyn Shape,
+    color: Color,
+    transform: Transform,
 ) -> ShapeBundle {
     base_builder(body, shape).build(
         ShapeColors::new(color),
         DrawMode::Fill(FillOptions::default()),
-        Transform {
-            translation: Vec3::Z,
-            scale: transform.scale.recip(),
-            ..Default::default()
-        },
+        transform,
     )
 }
 
@@ -242,7 +281,80 @@ fn base_builder(body: &CollisionShape, shape: &dyn Shape) -> GeometryBuilder {
                 });
             }
         }
+        CollisionShape::TriMesh { vertices, indices } => {
+            for triangle in indices {
+                builder.add(&shapes::Polygon {
+                    points: triangle
+                        .iter()
+                        .filter_map(|i| vertices.get(*i as usize))
+                        .map(|v| v.truncate())
+                        .collect(),
+                    closed: true,
+                });
+            }
+        }
+        CollisionShape::Polyline { vertices, indices } => {
+            #[allow(clippy::cast_possible_truncation)]
+            let segments: Vec<[u32; 2]> = indices
+                .clone()
+                .unwrap_or_else(|| (1..vertices.len() as u32).map(|i| [i - 1, i]).collect());
+
+            for [a, b] in segments {
+                if let (Some(a), Some(b)) = (vertices.get(a as usize), vertices.get(b as usize)) {
+                    add_segment(&mut builder, a.truncate(), b.truncate());
+                }
+            }
+        }
+        // Compound shapes are rendered as one child per inner shape (see `spawn_debug_shape`)
+        CollisionShape::Compound(_) => {}
+        CollisionShape::ConvexDecomposition { .. } => {
+            if let Some(compound) = shape.as_compound() {
+                for (position, part) in compound.shapes() {
+                    if let Some(polygon) = part.as_convex_polygon() {
+                        builder.add(&shapes::Polygon {
+                            points: polygon
+                                .points()
+                                .iter()
+                                .map(|point| (position * point).into_bevy())
+                                .collect(),
+                            closed: true,
+                        });
+                    }
+                }
+            }
+        }
+        CollisionShape::Segment { a, b } => {
+            add_segment(&mut builder, a.truncate(), b.truncate());
+        }
+        CollisionShape::Triangle { a, b, c } => {
+            builder.add(&shapes::Polygon {
+                points: vec![a.truncate(), b.truncate(), c.truncate()],
+                closed: true,
+            });
+        }
+        CollisionShape::HalfSpace { outward_normal } => {
+            // The half-space is infinite, so only a (very) large part of it is rendered
+            let normal = outward_normal.truncate().normalize_or_zero() * HALF_SPACE_EXTENT;
+            let tangent = normal.perp();
+            builder.add(&shapes::Polygon {
+                points: vec![tangent, -tangent, -tangent - normal, tangent - normal],
+                closed: true,
+            });
+        }
+        // The 3d-only shapes (cylinder and cone) exist when the 3d feature of `heron_core` is
+        // enabled by another crate, but there is nothing to render for them in 2d
+        #[allow(unreachable_patterns)]
+        _ => {}
     };
 
     builder
 }
+
+/// Segments have no area to fill, so they are rendered as thin rectangles
+fn add_segment(builder: &mut GeometryBuilder, a: Vec2, b: Vec2) {
+    let offset = (b - a).perp().normalize_or_zero() * SEGMENT_HALF_WIDTH;
+    builder.add(&shapes::Polygon {
+        points: vec![a + offset, b + offset, b - offset, a - offset],
+        closed: true,
+    });
+}
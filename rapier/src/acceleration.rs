@@ -1,21 +1,47 @@
 use bevy::prelude::*;
 
-use heron_core::{utils::NearZero, Acceleration};
+use heron_core::{utils::NearZero, Acceleration, ExternalForce, ForceSpace};
 
 use crate::convert::IntoRapier;
 use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
 use crate::rapier::{
     dynamics::RigidBody,
-    math::{AngVector, Vector},
+    math::{AngVector, Point, Vector},
 };
 
-pub(crate) fn update_rapier_force_and_torque(
-    mut bodies: ResMut<'_, RigidBodySet>,
-    accelerations: Query<'_, (&RigidBodyHandle, &Acceleration)>,
-) {
-    for (handle, acceleration) in accelerations.iter() {
-        if let Some(body) = bodies.get_mut(*handle) {
-            update_acceleration(body, acceleration)
+pub(crate) use forces::Forces;
+
+// We have to make a module here so that we can allow missing docs on the structs generated by the
+// derive macro
+#[allow(missing_docs)]
+mod forces {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+    use bevy::ecs::system::SystemParam;
+
+    /// Accelerations and external forces of the bodies
+    ///
+    /// Rapier resets the forces after each step, so they have to be applied again before every
+    /// step (including the substeps).
+    #[derive(SystemParam)]
+    pub struct Forces<'a> {
+        accelerations: Query<'a, (&'static RigidBodyHandle, &'static Acceleration)>,
+        external_forces: Query<'a, (&'static RigidBodyHandle, &'static ExternalForce)>,
+    }
+
+    impl Forces<'_> {
+        pub(crate) fn apply(&self, bodies: &mut RigidBodySet) {
+            for (handle, acceleration) in self.accelerations.iter() {
+                if let Some(body) = bodies.get_mut(*handle) {
+                    update_acceleration(body, acceleration);
+                }
+            }
+
+            for (handle, force) in self.external_forces.iter() {
+                if let Some(body) = bodies.get_mut(*handle) {
+                    apply_external_force(body, force);
+                }
+            }
         }
     }
 }
@@ -42,3 +68,26 @@ fn update_acceleration(body: &mut RigidBody, acceleration: &Acceleration) {
     body.apply_force(linear_acceleration * body.mass(), wake_up);
     body.apply_torque(inertia * angular_acceleration, wake_up)
 }
+
+fn apply_external_force(body: &mut RigidBody, external_force: &ExternalForce) {
+    let wake_up =
+        !(external_force.force.is_near_zero() && Vec3::from(external_force.torque).is_near_zero());
+    let force: Vector<f32> = external_force.force.into_rapier();
+    let torque: AngVector<f32> = external_force.torque.into_rapier();
+    let point: Point<f32> = external_force.point.into_rapier();
+
+    let (force, torque, point) = match external_force.space {
+        ForceSpace::World => (force, torque, point),
+        ForceSpace::Local => {
+            let position = body.position();
+            #[cfg(dim2)]
+            let world_torque = torque;
+            #[cfg(dim3)]
+            let world_torque = position * torque;
+            (position * force, world_torque, position * point)
+        }
+    };
+
+    body.apply_force_at_point(force, point, wake_up);
+    body.apply_torque(torque, wake_up);
+}
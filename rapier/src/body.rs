@@ -1,14 +1,20 @@
 use bevy::ecs::prelude::*;
+use bevy::math::{Quat, Vec3};
 use bevy::transform::prelude::*;
 use fnv::FnvHashMap;
 
-use heron_core::{PhysicMaterial, RigidBody, RotationConstraints, Velocity};
+use heron_core::{
+    ContinuousCollisionDetection, Damping, Dominance, GravityScale, MassProperties, PhysicMaterial,
+    RigidBody, RotationConstraints, SleepThreshold, Sleeping, TransformInterpolation, Velocity,
+};
 
 use crate::convert::{IntoBevy, IntoRapier};
+use crate::interpolation::InterpolationState;
 use crate::rapier::dynamics::{
     IslandManager, JointSet, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
 };
 use crate::rapier::geometry::{ColliderHandle, ColliderSet};
+use crate::sleeping::NewSleepingBody;
 
 pub(crate) type HandleMap = FnvHashMap<Entity, RigidBodyHandle>;
 
@@ -25,14 +31,37 @@ pub(crate) fn create(
             &RigidBody,
             Option<&Velocity>,
             Option<&RotationConstraints>,
+            Option<&Damping>,
+            Option<&ContinuousCollisionDetection>,
+            Option<&MassProperties>,
+            (Option<&Sleeping>, Option<&SleepThreshold>),
+            Option<&GravityScale>,
+            Option<&Dominance>,
         ),
         Without<RigidBodyHandle>,
     >,
 ) {
-    for (entity, transform, body, velocity, rotation_constraints) in query.iter() {
+    for (
+        entity,
+        transform,
+        body,
+        velocity,
+        rotation_constraints,
+        damping,
+        ccd,
+        mass_properties,
+        (sleeping, sleep_threshold),
+        gravity_scale,
+        dominance,
+    ) in query.iter()
+    {
         let mut builder = RigidBodyBuilder::new(body_status(*body))
             .user_data(entity.to_bits().into())
-            .position((transform.translation, transform.rotation).into_rapier());
+            .position((transform.translation, transform.rotation).into_rapier())
+            .ccd_enabled(ccd.is_some())
+            .sleeping(sleeping.is_some())
+            .gravity_scale(gravity_scale.copied().unwrap_or_default().0)
+            .dominance_group(dominance.copied().unwrap_or_default().0);
 
         #[allow(unused_variables)]
         if let Some(RotationConstraints {
@@ -57,10 +86,31 @@ pub(crate) fn create(
                 .angvel(v.angular.into_rapier());
         }
 
-        let rigid_body_handle = bodies.insert(builder.build());
+        if let Some(damping) = damping {
+            builder = builder
+                .linear_damping(damping.linear)
+                .angular_damping(damping.angular);
+        }
+
+        if let Some(mass_properties) = mass_properties {
+            // The colliders of the body have no density, so that these are the total mass properties
+            builder = builder.additional_mass_properties((*mass_properties).into_rapier());
+        }
+
+        let mut rigid_body = builder.build();
+
+        if let Some(SleepThreshold(threshold)) = sleep_threshold {
+            rigid_body.activation_mut().threshold = *threshold;
+        }
+
+        let rigid_body_handle = bodies.insert(rigid_body);
 
         handles.insert(entity, rigid_body_handle);
         commands.entity(entity).insert(rigid_body_handle);
+
+        if sleeping.is_some() {
+            commands.entity(entity).insert(NewSleepingBody);
+        }
     }
 }
 
@@ -75,11 +125,13 @@ pub(crate) fn remove_invalids_after_components_removed(
     bodies_removed: RemovedComponents<'_, RigidBody>,
     constraints_removed: RemovedComponents<'_, RotationConstraints>,
     materials_removed: RemovedComponents<'_, PhysicMaterial>,
+    mass_properties_removed: RemovedComponents<'_, MassProperties>,
 ) {
     bodies_removed
         .iter()
         .chain(constraints_removed.iter())
         .chain(materials_removed.iter())
+        .chain(mass_properties_removed.iter())
         .for_each(|entity| {
             if let Some(handle) = handles.remove(&entity) {
                 remove_collider_handles(&mut commands, &bodies, &colliders, handle);
@@ -104,6 +156,7 @@ pub(crate) fn remove_invalids_after_component_changed(
             Changed<RigidBody>,
             Changed<RotationConstraints>,
             Changed<PhysicMaterial>,
+            Changed<MassProperties>,
         )>,
     >,
 ) {
@@ -136,12 +189,110 @@ fn remove_collider_handles(
         });
 }
 
+pub(crate) fn enable_ccd(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    query: Query<'_, &RigidBodyHandle, Added<ContinuousCollisionDetection>>,
+) {
+    for handle in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            body.enable_ccd(true);
+        }
+    }
+}
+
+pub(crate) fn disable_ccd(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Query<'_, &RigidBodyHandle>,
+    removed: RemovedComponents<'_, ContinuousCollisionDetection>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.enable_ccd(false);
+            }
+        });
+}
+
+pub(crate) fn update_gravity_scale(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    query: Query<'_, (&RigidBodyHandle, &GravityScale), Changed<GravityScale>>,
+) {
+    for (handle, GravityScale(scale)) in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            body.set_gravity_scale(*scale, true);
+        }
+    }
+}
+
+pub(crate) fn reset_gravity_scale(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Query<'_, &RigidBodyHandle>,
+    removed: RemovedComponents<'_, GravityScale>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.set_gravity_scale(GravityScale::default().0, true);
+            }
+        });
+}
+
+pub(crate) fn update_dominance(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    query: Query<'_, (&RigidBodyHandle, &Dominance), Changed<Dominance>>,
+) {
+    for (handle, Dominance(group)) in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            body.set_dominance_group(*group);
+        }
+    }
+}
+
+pub(crate) fn reset_dominance(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Query<'_, &RigidBodyHandle>,
+    removed: RemovedComponents<'_, Dominance>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.set_dominance_group(Dominance::default().0);
+            }
+        });
+}
+
 pub(crate) fn update_rapier_position(
     mut bodies: ResMut<'_, RigidBodySet>,
-    query: Query<'_, (&GlobalTransform, &RigidBodyHandle), Changed<GlobalTransform>>,
+    mut query: Query<
+        '_,
+        (
+            &GlobalTransform,
+            &RigidBodyHandle,
+            Option<&mut InterpolationState>,
+        ),
+        Changed<GlobalTransform>,
+    >,
 ) {
-    for (transform, handle) in query.iter() {
+    for (transform, handle, interpolation) in query.iter_mut() {
+        if let Some(mut interpolation) = interpolation {
+            if interpolation.is_rendered(transform) {
+                // The transform has only been updated by the interpolation
+                continue;
+            }
+            interpolation.reset(transform.translation, transform.rotation);
+        }
+
         if let Some(body) = bodies.get_mut(*handle) {
+            if is_at_pose(body, transform) {
+                continue;
+            }
+
             let isometry = (transform.translation, transform.rotation).into_rapier();
             if body.is_kinematic() {
                 body.set_next_kinematic_position(isometry);
@@ -152,6 +303,22 @@ pub(crate) fn update_rapier_position(
     }
 }
 
+/// Returns true if the body is already at the pose of the transform
+///
+/// It happens when the transform has only been updated from the physics world. Setting the
+/// position again would wake up the body, and in 2d, slightly change its rotation.
+fn is_at_pose(body: &crate::rapier::dynamics::RigidBody, transform: &GlobalTransform) -> bool {
+    let (translation, rotation) = body.position().into_bevy();
+
+    #[cfg(dim2)]
+    let same_translation = translation.truncate() == transform.translation.truncate();
+
+    #[cfg(dim3)]
+    let same_translation = translation == transform.translation;
+
+    same_translation && rotation == transform.rotation
+}
+
 pub(crate) fn update_bevy_transform(
     bodies: Res<'_, RigidBodySet>,
     mut query: Query<
@@ -162,9 +329,10 @@ pub(crate) fn update_bevy_transform(
             &RigidBodyHandle,
             Option<&RigidBody>,
         ),
+        Without<TransformInterpolation>,
     >,
 ) {
-    for (mut local, mut global, handle, body_type) in query.iter_mut() {
+    for (local, global, handle, body_type) in query.iter_mut() {
         if !body_type.copied().unwrap_or_default().can_have_velocity() {
             continue;
         }
@@ -174,39 +342,46 @@ pub(crate) fn update_bevy_transform(
             Some(body) => body,
         };
 
-        #[cfg(dim3)]
         let (translation, rotation) = body.position().into_bevy();
-        #[cfg(dim2)]
-        let (mut translation, rotation) = body.position().into_bevy();
-
-        #[cfg(dim2)]
-        {
-            // In 2D, preserve the transform `z` component that may have been set by the user
-            translation.z = global.translation.z;
-        }
+        set_bevy_transform(local, global, translation, rotation);
+    }
+}
 
-        if translation == global.translation && rotation == global.rotation {
-            continue;
-        }
+/// Set the pose of a body to the bevy transforms, without marking them as changed if the pose is
+/// the same
+///
+/// Returns the global pose that was set
+pub(crate) fn set_bevy_transform(
+    local: Option<Mut<'_, Transform>>,
+    mut global: Mut<'_, GlobalTransform>,
+    translation: Vec3,
+    rotation: Quat,
+) -> (Vec3, Quat) {
+    // In 2D, preserve the transform `z` component that may have been set by the user
+    #[cfg(dim2)]
+    let translation = translation.truncate().extend(global.translation.z);
 
-        if let Some(local) = &mut local {
-            if local.translation == global.translation {
-                local.translation = translation;
-            } else {
-                local.translation = translation - (global.translation - local.translation);
-            }
+    if translation == global.translation && rotation == global.rotation {
+        return (translation, rotation);
+    }
 
-            if local.rotation == global.rotation {
-                local.rotation = rotation;
-            } else {
-                local.rotation =
-                    rotation * (global.rotation * local.rotation.conjugate()).conjugate();
-            }
+    if let Some(mut local) = local {
+        if local.translation == global.translation {
+            local.translation = translation;
+        } else {
+            local.translation = translation - (global.translation - local.translation);
         }
 
-        global.translation = translation;
-        global.rotation = rotation;
+        if local.rotation == global.rotation {
+            local.rotation = rotation;
+        } else {
+            local.rotation = rotation * (global.rotation * local.rotation.conjugate()).conjugate();
+        }
     }
+
+    global.translation = translation;
+    global.rotation = rotation;
+    (translation, rotation)
 }
 
 fn body_status(body_type: RigidBody) -> RigidBodyType {
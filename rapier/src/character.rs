@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+
+use bevy::ecs::prelude::*;
+use bevy::math::{Quat, Vec3};
+use bevy::transform::prelude::*;
+use fnv::FnvHashMap;
+
+use heron_core::{
+    CharacterController, CharacterControllerOutput, CharacterHit, CollisionLayers, CollisionShape,
+};
+
+use crate::pipeline::{PhysicsWorld, ShapeCastCollisionType};
+use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
+
+/// Distance under which movements are ignored
+const EPSILON: f32 = 1e-5;
+
+/// Position of the ground of each character, when last seen, so that characters can ride moving
+/// platforms
+pub(crate) type GroundMap = FnvHashMap<Entity, (Entity, GlobalTransform)>;
+
+pub(crate) fn move_characters(
+    mut commands: Commands<'_>,
+    world: PhysicsWorld<'_>,
+    mut grounds: Local<'_, GroundMap>,
+    removed: RemovedComponents<'_, CharacterController>,
+    bodies: Res<'_, RigidBodySet>,
+    mut characters: Query<
+        '_,
+        (
+            Entity,
+            &CollisionShape,
+            &GlobalTransform,
+            &mut Transform,
+            &mut CharacterController,
+            Option<&CollisionLayers>,
+            Option<&RigidBodyHandle>,
+            Option<&Parent>,
+            Option<&mut CharacterControllerOutput>,
+        ),
+    >,
+    transforms: Query<'_, &GlobalTransform>,
+) {
+    for entity in removed.iter() {
+        grounds.remove(&entity);
+    }
+
+    for (entity, shape, global, mut local, mut controller, layers, handle, parent, output) in
+        characters.iter_mut()
+    {
+        let mut translation = controller.translation.take().unwrap_or_default();
+
+        // Follow the movement of the ground since the last step
+        if let Some((ground, last_transform)) = grounds.remove(&entity) {
+            if let Ok(transform) = transforms.get(ground) {
+                let ground_movement =
+                    transform.compute_matrix() * last_transform.compute_matrix().inverse();
+                translation +=
+                    ground_movement.transform_point3(global.translation) - global.translation;
+            }
+        }
+
+        let was_grounded = output.as_ref().map_or(false, |it| it.grounded);
+
+        let mut ignored: Vec<Entity> = handle
+            .and_then(|handle| bodies.get(*handle))
+            .map(|body| {
+                body.colliders()
+                    .iter()
+                    .filter_map(|collider| world.collider_entity(*collider))
+                    .collect()
+            })
+            .unwrap_or_default();
+        ignored.push(entity);
+
+        let mover = Mover {
+            world: &world,
+            ignored,
+            shape,
+            rotation: global.rotation,
+            layers: layers.copied().unwrap_or_default(),
+            controller: *controller,
+        };
+
+        let result = mover.move_and_slide(global.translation, translation, was_grounded);
+        let effective_translation = result.effective_translation;
+
+        if let Some(ground) = result.ground_entity {
+            if let Ok(transform) = transforms.get(ground) {
+                grounds.insert(entity, (ground, *transform));
+            }
+        }
+
+        if let Some(mut output) = output {
+            *output = result;
+        } else {
+            commands.entity(entity).insert(result);
+        }
+
+        if effective_translation.length_squared() > EPSILON * EPSILON {
+            // The translation is in world-space, and the transform is relative to the parent
+            local.translation += match parent.and_then(|parent| transforms.get(parent.0).ok()) {
+                Some(parent) => parent
+                    .compute_matrix()
+                    .inverse()
+                    .transform_vector3(effective_translation),
+                None => effective_translation,
+            };
+        }
+    }
+}
+
+struct Mover<'a, 'w> {
+    world: &'a PhysicsWorld<'w>,
+    /// Collision shape entities of the character itself
+    ignored: Vec<Entity>,
+    shape: &'a CollisionShape,
+    rotation: Quat,
+    layers: CollisionLayers,
+    controller: CharacterController,
+}
+
+/// Obstacle found by casting the character shape
+struct Obstacle {
+    /// Distance that can be traveled before hitting the obstacle (minus the skin width)
+    distance: f32,
+    hit: CharacterHit,
+}
+
+impl Mover<'_, '_> {
+    fn move_and_slide(
+        &self,
+        start: Vec3,
+        translation: Vec3,
+        was_grounded: bool,
+    ) -> CharacterControllerOutput {
+        let up = self.controller.up;
+        let mut output = CharacterControllerOutput::default();
+        let mut position = self.depenetrate(start);
+        let mut remaining = translation;
+
+        for _ in 0..self.controller.max_iterations {
+            let direction = match remaining.try_normalize() {
+                Some(direction) if remaining.length() > EPSILON => direction,
+                _ => break,
+            };
+
+            let obstacle = match self.cast(position, remaining) {
+                Some(obstacle) => obstacle,
+                None => {
+                    position += remaining;
+                    remaining = Vec3::ZERO;
+                    break;
+                }
+            };
+
+            position += direction * obstacle.distance;
+            remaining -= direction * obstacle.distance;
+
+            let normal = obstacle.hit.normal;
+            let is_ground = self.is_ground(normal);
+            if is_ground {
+                output.grounded = true;
+                output.ground_normal = Some(normal);
+                output.ground_entity = Some(obstacle.hit.entity);
+            }
+            output.hits.push(obstacle.hit);
+
+            if !is_ground {
+                let horizontal = remaining - up * remaining.dot(up);
+                if let Some(stepped) = self.step_up(position, horizontal) {
+                    position = stepped;
+                    remaining -= horizontal;
+                    continue;
+                }
+            }
+
+            // Slide along the surface
+            remaining -= normal * remaining.dot(normal);
+
+            // Slopes that are too steep cannot be climbed
+            if !is_ground && remaining.dot(up) > 0.0 {
+                remaining -= up * remaining.dot(up);
+            }
+        }
+
+        if translation.dot(up) <= EPSILON && !output.grounded {
+            let distance = if was_grounded {
+                self.controller.snap_to_ground + self.controller.skin_width * 2.0
+            } else {
+                self.controller.skin_width * 2.0
+            };
+
+            if let Some(obstacle) = self.cast(position, -up * distance) {
+                if self.is_ground(obstacle.hit.normal) {
+                    position -= up * obstacle.distance;
+                    output.grounded = true;
+                    output.ground_normal = Some(obstacle.hit.normal);
+                    output.ground_entity = Some(obstacle.hit.entity);
+                }
+            }
+        }
+
+        output.effective_translation = position - start;
+        output
+    }
+
+    /// Try to step onto an obstacle, returns the new position if possible
+    fn step_up(&self, position: Vec3, horizontal: Vec3) -> Option<Vec3> {
+        let up = self.controller.up;
+        let max_height = self.controller.max_step_height;
+        if max_height <= 0.0 || horizontal.length() <= EPSILON {
+            return None;
+        }
+
+        let height = self
+            .cast(position, up * max_height)
+            .map_or(max_height, |obstacle| obstacle.distance);
+        let raised = position + up * height;
+
+        if self.cast(raised, horizontal).is_some() {
+            return None;
+        }
+        let advanced = raised + horizontal;
+
+        let landing = self.cast(advanced, -up * height)?;
+        if self.is_ground(landing.hit.normal) {
+            Some(advanced - up * landing.distance)
+        } else {
+            None
+        }
+    }
+
+    fn is_ground(&self, normal: Vec3) -> bool {
+        normal.dot(self.controller.up) >= self.controller.max_slope_angle.cos()
+    }
+
+    fn is_obstacle(&self, entity: Entity) -> bool {
+        !self.ignored.contains(&entity)
+    }
+
+    /// Push the character out of the obstacles it penetrates
+    fn depenetrate(&self, mut position: Vec3) -> Vec3 {
+        for _ in 0..self.controller.max_iterations {
+            let deepest = self
+                .world
+                .contacts_with_filter(
+                    self.shape,
+                    position,
+                    self.rotation,
+                    self.layers,
+                    &|entity| self.is_obstacle(entity),
+                )
+                .into_iter()
+                .filter(|contact| contact.depth > EPSILON)
+                .max_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal));
+
+            match deepest {
+                Some(contact) => {
+                    position += contact.normal * (contact.depth + self.controller.skin_width);
+                }
+                None => break,
+            }
+        }
+        position
+    }
+
+    /// Normal of the contact with an obstacle penetrated by the character
+    fn penetration_normal(&self, position: Vec3, obstacle: Entity) -> Option<Vec3> {
+        self.world
+            .contacts_with_filter(
+                self.shape,
+                position,
+                self.rotation,
+                self.layers,
+                &|entity| entity == obstacle,
+            )
+            .into_iter()
+            .next()
+            .map(|contact| contact.normal)
+    }
+
+    /// Cast the character shape, ignoring the character itself
+    fn cast(&self, position: Vec3, translation: Vec3) -> Option<Obstacle> {
+        let direction = translation.try_normalize()?;
+        let mut leaving = Vec::new();
+
+        loop {
+            let info = self.world.shape_cast_with_filter(
+                self.shape,
+                position,
+                self.rotation,
+                translation + direction * self.controller.skin_width,
+                self.layers,
+                |entity| self.is_obstacle(entity) && !leaving.contains(&entity),
+            )?;
+
+            match info.collision_type {
+                ShapeCastCollisionType::AlreadyPenetrating => {
+                    match self.penetration_normal(position, info.entity) {
+                        // The character cannot move further into the shapes it already penetrates
+                        Some(normal) if translation.dot(normal) < 0.0 => {
+                            return Some(Obstacle {
+                                distance: 0.0,
+                                hit: CharacterHit {
+                                    entity: info.entity,
+                                    normal,
+                                    point: None,
+                                },
+                            });
+                        }
+                        // But it is allowed to move out of them
+                        _ => leaving.push(info.entity),
+                    }
+                }
+                ShapeCastCollisionType::Collided(collision) => {
+                    let distance = ((collision.self_end_position - position).length()
+                        - self.controller.skin_width)
+                        .max(0.0)
+                        .min(translation.length());
+
+                    // The world obstacle is the first shape of the cast, so `other_normal` is the
+                    // normal of the character shape, in its local space, pointing toward the obstacle
+                    let normal = -(self.rotation * collision.other_normal);
+
+                    return Some(Obstacle {
+                        distance,
+                        hit: CharacterHit {
+                            entity: info.entity,
+                            normal,
+                            point: Some(
+                                collision.self_end_position + self.rotation * collision.other_point,
+                            ),
+                        },
+                    });
+                }
+            }
+        }
+    }
+}
@@ -7,13 +7,17 @@
 
 use bevy::math::prelude::*;
 
-use heron_core::{AxisAngle, CollisionLayers};
+use heron_core::{
+    AxisAngle, CollisionLayers, CombineRule, ConvexDecompositionParameters, MassProperties,
+};
 
 use crate::nalgebra::{
     self, Point2, Point3, Quaternion, UnitComplex, UnitQuaternion, Vector2, Vector3,
 };
+use crate::rapier::dynamics::{CoefficientCombineRule, MassProperties as RapierMassProperties};
 use crate::rapier::geometry::InteractionGroups;
 use crate::rapier::math::{Isometry, Translation, Vector};
+use crate::rapier::parry::transformation::vhacd::VHACDParameters;
 
 pub trait IntoBevy<T> {
     #[must_use]
@@ -187,6 +191,42 @@ impl IntoBevy<CollisionLayers> for InteractionGroups {
     }
 }
 
+impl IntoRapier<VHACDParameters> for ConvexDecompositionParameters {
+    fn into_rapier(self) -> VHACDParameters {
+        VHACDParameters {
+            resolution: self.resolution,
+            concavity: self.concavity,
+            ..VHACDParameters::default()
+        }
+    }
+}
+
+impl IntoRapier<RapierMassProperties> for MassProperties {
+    fn into_rapier(self) -> RapierMassProperties {
+        #[cfg(dim2)]
+        let principal_inertia = self.principal_inertia.z;
+        #[cfg(dim3)]
+        let principal_inertia = self.principal_inertia.into_rapier();
+
+        RapierMassProperties::new(
+            self.local_center_of_mass.into_rapier(),
+            self.mass,
+            principal_inertia,
+        )
+    }
+}
+
+impl IntoRapier<CoefficientCombineRule> for CombineRule {
+    fn into_rapier(self) -> CoefficientCombineRule {
+        match self {
+            CombineRule::Average => CoefficientCombineRule::Average,
+            CombineRule::Min => CoefficientCombineRule::Min,
+            CombineRule::Multiply => CoefficientCombineRule::Multiply,
+            CombineRule::Max => CoefficientCombineRule::Max,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(dim3)]
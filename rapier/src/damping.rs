@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use heron_core::Damping;
+
+use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
+
+pub(crate) fn update_rapier_damping(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    query: Query<'_, (&RigidBodyHandle, &Damping), Changed<Damping>>,
+) {
+    for (handle, damping) in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            body.set_linear_damping(damping.linear);
+            body.set_angular_damping(damping.angular);
+        }
+    }
+}
+
+pub(crate) fn reset_rapier_damping(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Query<'_, &RigidBodyHandle>,
+    removed: RemovedComponents<'_, Damping>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.set_linear_damping(0.0);
+                body.set_angular_damping(0.0);
+            }
+        });
+}
@@ -0,0 +1,412 @@
+use bevy::ecs::prelude::*;
+use bevy::math::Vec3;
+
+use heron_core::{ActivePhysicsHooks, CollisionData, OneWayPlatform, SurfaceVelocity};
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::pipeline::point_into_bevy;
+use crate::rapier::dynamics::RigidBodySet;
+use crate::rapier::geometry::{ColliderHandle, ColliderSet, SolverFlags};
+use crate::rapier::math::Vector;
+use crate::rapier::pipeline::{
+    ActiveHooks, ContactModificationContext, PairFilterContext, PhysicsHooks as RapierPhysicsHooks,
+};
+
+use bevy::ecs::system::SystemParam;
+
+pub(crate) use step_hooks::StepHooks;
+
+/// User-defined hooks, called by the physics engine during the narrow-phase
+///
+/// To be used, the hooks must be inserted as a [`PhysicsHooksResource`], and enabled on the
+/// collision shapes with the [`ActivePhysicsHooks`] component. A hook is only called for the pairs
+/// of collision shapes where at least one of them enables it.
+///
+/// The hooks are called from the physics step, possibly from multiple threads. They cannot access
+/// the bevy world, but may hold any data they need (in thread-safe containers).
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_core::CollisionData;
+/// # use heron_rapier::{PhysicsHooks, PhysicsHooksResource};
+/// struct IgnoreSameBody;
+///
+/// impl PhysicsHooks for IgnoreSameBody {
+///     fn filter_contact_pair(&self, data1: &CollisionData, data2: &CollisionData) -> bool {
+///         data1.rigid_body_entity() != data2.rigid_body_entity()
+///     }
+/// }
+///
+/// fn main() {
+///     App::build()
+///         .insert_resource(PhysicsHooksResource::new(IgnoreSameBody))
+///         // ...
+///         .run();
+/// }
+/// ```
+pub trait PhysicsHooks: Send + Sync + 'static {
+    /// Returns `false` if the contacts between the two collision shapes should not be computed
+    ///
+    /// This is called for pairs of collision shapes where at least one of them has the
+    /// [`ActivePhysicsHooks::filter_contact_pairs`] flag enabled. When `false` is returned, the
+    /// shapes don't collide, and no [`CollisionEvent`](heron_core::CollisionEvent) is fired.
+    ///
+    /// The default implementation returns `true`.
+    fn filter_contact_pair(&self, _data1: &CollisionData, _data2: &CollisionData) -> bool {
+        true
+    }
+
+    /// Modify the contacts between two collision shapes, before they are solved
+    ///
+    /// This is called for pairs of collision shapes where at least one of them has the
+    /// [`ActivePhysicsHooks::modify_solver_contacts`] flag enabled.
+    ///
+    /// The default implementation does nothing.
+    fn modify_solver_contacts(&self, _context: &mut ContactModification<'_, '_>) {}
+}
+
+/// Resource holding the user-defined [`PhysicsHooks`]
+pub struct PhysicsHooksResource(Box<dyn PhysicsHooks>);
+
+impl PhysicsHooksResource {
+    /// Create the resource for the given hooks
+    #[must_use]
+    pub fn new(hooks: impl PhysicsHooks) -> Self {
+        Self(Box::new(hooks))
+    }
+}
+
+/// Contacts between two collision shapes, that may be modified before being solved
+///
+/// See [`PhysicsHooks::modify_solver_contacts`]
+pub struct ContactModification<'c, 'a> {
+    data1: CollisionData,
+    data2: CollisionData,
+    context: &'c mut ContactModificationContext<'a, RigidBodySet, ColliderSet>,
+}
+
+impl ContactModification<'_, '_> {
+    /// Data of the two collision shapes in contact
+    ///
+    /// The contact normal points from the first to the second.
+    #[must_use]
+    pub fn collision_data(&self) -> (CollisionData, CollisionData) {
+        (self.data1, self.data2)
+    }
+
+    /// Contact normal, pointing from the first collision shape to the second, in world-space
+    #[must_use]
+    pub fn normal(&self) -> Vec3 {
+        (*self.context.normal).into_bevy()
+    }
+
+    /// Set the contact normal, pointing from the first collision shape to the second, in
+    /// world-space
+    pub fn set_normal(&mut self, normal: Vec3) {
+        *self.context.normal = normal.into_rapier();
+    }
+
+    /// Contact points that will be solved
+    #[must_use]
+    pub fn solver_contacts(&self) -> Vec<SolverContact> {
+        self.context
+            .solver_contacts
+            .iter()
+            .map(SolverContact::from_rapier)
+            .collect()
+    }
+
+    /// Modify the contact points that will be solved
+    pub fn update_solver_contacts(&mut self, mut update: impl FnMut(&mut SolverContact)) {
+        for contact in self.context.solver_contacts.iter_mut() {
+            let mut updated = SolverContact::from_rapier(contact);
+            update(&mut updated);
+            updated.apply_to(contact);
+        }
+    }
+
+    /// Only keep the contact points for which the predicate returns `true`, the others are ignored
+    pub fn retain_solver_contacts(&mut self, mut predicate: impl FnMut(&SolverContact) -> bool) {
+        self.context
+            .solver_contacts
+            .retain(|contact| predicate(&SolverContact::from_rapier(contact)));
+    }
+}
+
+/// A contact point between two collision shapes, that will be solved
+///
+/// All the contact points of a [`ContactModification`] share the same
+/// [normal](ContactModification::normal).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolverContact {
+    /// Location of the contact point, in world-space
+    pub point: Vec3,
+
+    /// Distance between the two collision shapes at this point, along the contact normal
+    ///
+    /// It is negative if the shapes penetrate each other.
+    pub distance: f32,
+
+    /// Friction coefficient at this point
+    pub friction: f32,
+
+    /// Restitution coefficient at this point
+    pub restitution: f32,
+
+    /// Desired velocity of the second collision shape relative to the first, tangent to the
+    /// contact, in world-space
+    ///
+    /// It is zero by default, and can be used to simulate a conveyor belt.
+    pub tangent_velocity: Vec3,
+}
+
+impl SolverContact {
+    fn from_rapier(contact: &crate::rapier::geometry::SolverContact) -> Self {
+        Self {
+            point: point_into_bevy(contact.point),
+            distance: contact.dist,
+            friction: contact.friction,
+            restitution: contact.restitution,
+            tangent_velocity: contact.tangent_velocity.into_bevy(),
+        }
+    }
+
+    fn apply_to(self, contact: &mut crate::rapier::geometry::SolverContact) {
+        contact.point = self.point.into_rapier();
+        contact.dist = self.distance;
+        contact.friction = self.friction;
+        contact.restitution = self.restitution;
+        contact.tangent_velocity = self.tangent_velocity.into_rapier();
+    }
+}
+
+/// Maximum angle between the contact normal and the allowed direction of a [`OneWayPlatform`] for
+/// the contact to be solved
+const ONE_WAY_PLATFORM_ALLOWED_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+
+// We have to make a module here so that we can allow missing docs on the structs generated by the
+// derive macro
+#[allow(missing_docs)]
+mod step_hooks {
+    #[allow(clippy::wildcard_imports)]
+    // Fine right here because this module is a workaround anyway
+    use super::*;
+
+    /// Hooks to give to the physics pipeline when stepping the simulation
+    #[derive(SystemParam)]
+    pub struct StepHooks<'a> {
+        user_hooks: Option<Res<'a, PhysicsHooksResource>>,
+        active_hooks: Query<'a, &'static ActivePhysicsHooks>,
+        one_way_platforms: Query<'a, &'static OneWayPlatform>,
+        surface_velocities: Query<'a, &'static SurfaceVelocity>,
+    }
+
+    impl StepHooks<'_> {
+        fn user_filter(
+            &self,
+            bodies: &RigidBodySet,
+            colliders: &ColliderSet,
+            h1: ColliderHandle,
+            h2: ColliderHandle,
+        ) -> bool {
+            let hooks = match &self.user_hooks {
+                Some(hooks) => hooks,
+                None => return true,
+            };
+
+            match (
+                collision_data(bodies, colliders, h1),
+                collision_data(bodies, colliders, h2),
+            ) {
+                (Some(data1), Some(data2)) => hooks.0.filter_contact_pair(&data1, &data2),
+                _ => true,
+            }
+        }
+
+        fn is_modification_active(&self, data: &CollisionData) -> bool {
+            self.active_hooks
+                .get(data.collision_shape_entity())
+                .map_or(false, |hooks| hooks.modify_solver_contacts)
+        }
+
+        /// Cancel the contacts that go through a one-way platform in the allowed direction
+        fn apply_one_way_platform(
+            &self,
+            context: &mut ContactModificationContext<'_, RigidBodySet, ColliderSet>,
+            data1: &CollisionData,
+            data2: &CollisionData,
+        ) {
+            let (platform, collider, sign) = if let Ok(platform) =
+                self.one_way_platforms.get(data1.collision_shape_entity())
+            {
+                (platform, context.collider1, 1.0)
+            } else if let Ok(platform) = self.one_way_platforms.get(data2.collision_shape_entity())
+            {
+                (platform, context.collider2, -1.0)
+            } else {
+                return;
+            };
+
+            let (platform_position, collider1_position) = match (
+                context.colliders.get(collider),
+                context.colliders.get(context.collider1),
+            ) {
+                (Some(platform), Some(collider1)) => (*platform.position(), *collider1.position()),
+                _ => return,
+            };
+
+            // The contact normal points outward of the first collider, and the allowed normal is
+            // expected in its local space
+            let allowed_direction: Vector<f32> = platform.allowed_direction.into_rapier();
+            let allowed_local_n1 = collider1_position.inverse_transform_vector(
+                &(platform_position.transform_vector(&allowed_direction) * sign),
+            );
+
+            context.update_as_oneway_platform(
+                &allowed_local_n1.normalize(),
+                ONE_WAY_PLATFORM_ALLOWED_ANGLE,
+            );
+        }
+
+        /// Set the tangent velocity of the contacts, so that the other body is dragged along by
+        /// the surface(s)
+        fn apply_surface_velocity(
+            &self,
+            context: &mut ContactModificationContext<'_, RigidBodySet, ColliderSet>,
+            data1: &CollisionData,
+            data2: &CollisionData,
+        ) {
+            let velocity1 = self.surface_velocity(context.colliders, context.collider1, data1);
+            let velocity2 = self.surface_velocity(context.colliders, context.collider2, data2);
+            if velocity1.is_none() && velocity2.is_none() {
+                return;
+            }
+
+            // The solver drives the tangent velocity of the second collider relative to the first
+            let velocity =
+                velocity1.unwrap_or_else(Vector::zeros) - velocity2.unwrap_or_else(Vector::zeros);
+            let normal = *context.normal;
+            let tangent_velocity = velocity - normal * velocity.dot(&normal);
+
+            for contact in context.solver_contacts.iter_mut() {
+                contact.tangent_velocity += tangent_velocity;
+            }
+        }
+
+        /// World-space velocity of the surface of the collider, if it has a [`SurfaceVelocity`]
+        fn surface_velocity(
+            &self,
+            colliders: &ColliderSet,
+            handle: ColliderHandle,
+            data: &CollisionData,
+        ) -> Option<Vector<f32>> {
+            let SurfaceVelocity(velocity) = self
+                .surface_velocities
+                .get(data.collision_shape_entity())
+                .ok()?;
+            let local_velocity: Vector<f32> = (*velocity).into_rapier();
+            Some(
+                colliders
+                    .get(handle)?
+                    .position()
+                    .transform_vector(&local_velocity),
+            )
+        }
+    }
+
+    impl RapierPhysicsHooks<RigidBodySet, ColliderSet> for StepHooks<'_> {
+        fn filter_contact_pair(
+            &self,
+            context: &PairFilterContext<'_, RigidBodySet, ColliderSet>,
+        ) -> Option<SolverFlags> {
+            if self.user_filter(
+                context.bodies,
+                context.colliders,
+                context.collider1,
+                context.collider2,
+            ) {
+                Some(SolverFlags::COMPUTE_IMPULSES)
+            } else {
+                None
+            }
+        }
+
+        fn filter_intersection_pair(
+            &self,
+            context: &PairFilterContext<'_, RigidBodySet, ColliderSet>,
+        ) -> bool {
+            self.user_filter(
+                context.bodies,
+                context.colliders,
+                context.collider1,
+                context.collider2,
+            )
+        }
+
+        fn modify_solver_contacts(
+            &self,
+            context: &mut ContactModificationContext<'_, RigidBodySet, ColliderSet>,
+        ) {
+            let (data1, data2) = match (
+                collision_data(context.bodies, context.colliders, context.collider1),
+                collision_data(context.bodies, context.colliders, context.collider2),
+            ) {
+                (Some(data1), Some(data2)) => (data1, data2),
+                _ => return,
+            };
+
+            self.apply_one_way_platform(context, &data1, &data2);
+            self.apply_surface_velocity(context, &data1, &data2);
+
+            if let Some(hooks) = &self.user_hooks {
+                if self.is_modification_active(&data1) || self.is_modification_active(&data2) {
+                    hooks.0.modify_solver_contacts(&mut ContactModification {
+                        data1,
+                        data2,
+                        context,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Rapier hooks flags to set on the collider of a collision shape
+///
+/// `modifies_contacts` is `true` if the collision shape has a built-in behavior that modifies the
+/// contacts, such as [`OneWayPlatform`] or [`SurfaceVelocity`]
+pub(crate) fn active_hooks(
+    hooks: Option<&ActivePhysicsHooks>,
+    modifies_contacts: bool,
+) -> ActiveHooks {
+    let mut flags = ActiveHooks::empty();
+    if let Some(hooks) = hooks {
+        if hooks.filter_contact_pairs {
+            flags |= ActiveHooks::FILTER_CONTACT_PAIRS | ActiveHooks::FILTER_INTERSECTION_PAIR;
+        }
+        if hooks.modify_solver_contacts {
+            flags |= ActiveHooks::MODIFY_SOLVER_CONTACTS;
+        }
+    }
+    if modifies_contacts {
+        flags |= ActiveHooks::MODIFY_SOLVER_CONTACTS;
+    }
+    flags
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn collision_data(
+    bodies: &RigidBodySet,
+    colliders: &ColliderSet,
+    handle: ColliderHandle,
+) -> Option<CollisionData> {
+    let collider = colliders.get(handle)?;
+    let body = bodies.get(collider.parent()?)?;
+    Some(CollisionData::new(
+        Entity::from_bits(body.user_data as u64),
+        Entity::from_bits(collider.user_data as u64),
+        collider.collision_groups().into_bevy(),
+    ))
+}
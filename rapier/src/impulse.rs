@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use heron_core::Impulse;
+
+use crate::convert::IntoRapier;
+use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
+use crate::rapier::math::{AngVector, Point, Vector};
+
+pub(crate) fn apply_impulses(
+    mut commands: Commands<'_>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    impulses: Query<'_, (Entity, &RigidBodyHandle, &Impulse)>,
+) {
+    for (entity, handle, impulse) in impulses.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            let linear: Vector<f32> = impulse.linear.into_rapier();
+            let angular: AngVector<f32> = impulse.angular.into_rapier();
+
+            if let Some(point) = impulse.point {
+                let point: Point<f32> = point.into_rapier();
+                body.apply_impulse_at_point(linear, point, true);
+            } else {
+                body.apply_impulse(linear, true);
+            }
+            body.apply_torque_impulse(angular, true);
+        }
+
+        commands.entity(entity).remove::<Impulse>();
+    }
+}
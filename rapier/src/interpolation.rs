@@ -0,0 +1,151 @@
+use bevy::ecs::prelude::*;
+use bevy::math::{Quat, Vec3};
+use bevy::transform::prelude::*;
+
+use heron_core::{PhysicsSteps, PhysicsTime, RigidBody, TransformInterpolation};
+
+use crate::body::set_bevy_transform;
+use crate::convert::IntoBevy;
+use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
+
+/// Maximum difference between the rendered pose and the transform for the transform to be
+/// considered as not changed by the user
+const EPSILON: f32 = 1e-5;
+
+/// Poses of the last two physics steps of a body with [`TransformInterpolation`]
+pub(crate) struct InterpolationState {
+    previous: (Vec3, Quat),
+    current: (Vec3, Quat),
+    rendered: (Vec3, Quat),
+}
+
+impl InterpolationState {
+    fn new(translation: Vec3, rotation: Quat) -> Self {
+        Self {
+            previous: (translation, rotation),
+            current: (translation, rotation),
+            rendered: (translation, rotation),
+        }
+    }
+
+    /// Returns true if the transform is the one rendered by the interpolation
+    pub(crate) fn is_rendered(&self, transform: &GlobalTransform) -> bool {
+        transform.translation.abs_diff_eq(self.rendered.0, EPSILON)
+            && transform.rotation.abs_diff_eq(self.rendered.1, EPSILON)
+    }
+
+    /// Forget the previous poses, after the body has been teleported
+    pub(crate) fn reset(&mut self, translation: Vec3, rotation: Quat) {
+        *self = Self::new(translation, rotation);
+    }
+
+    fn push(&mut self, translation: Vec3, rotation: Quat) {
+        self.previous = self.current;
+        self.current = (translation, rotation);
+    }
+
+    fn interpolate(&self, fraction: f32) -> (Vec3, Quat) {
+        (
+            self.previous.0.lerp(self.current.0, fraction),
+            self.previous.1.slerp(self.current.1, fraction),
+        )
+    }
+}
+
+/// Record the pose of the bodies after the physics step
+pub(crate) fn record_poses(
+    mut commands: Commands<'_>,
+    bodies: Res<'_, RigidBodySet>,
+    mut query: Query<
+        '_,
+        (Entity, &RigidBodyHandle, Option<&mut InterpolationState>),
+        With<TransformInterpolation>,
+    >,
+) {
+    for (entity, handle, state) in query.iter_mut() {
+        let (translation, rotation) = match bodies.get(*handle) {
+            Some(body) => body.position().into_bevy(),
+            None => continue,
+        };
+
+        match state {
+            Some(mut state) => state.push(translation, rotation),
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(InterpolationState::new(translation, rotation));
+            }
+        }
+    }
+}
+
+/// Update the transform of the bodies, by blending the poses of the last two physics steps
+///
+/// Runs at every frame, including the frames without physics step
+pub(crate) fn interpolate_transforms(
+    physics_steps: Res<'_, PhysicsSteps>,
+    physics_time: Res<'_, PhysicsTime>,
+    mut query: Query<
+        '_,
+        (
+            Option<&mut Transform>,
+            &mut GlobalTransform,
+            &mut InterpolationState,
+            Option<&RigidBody>,
+        ),
+        With<TransformInterpolation>,
+    >,
+) {
+    // Don't move back and forth while the simulation is paused
+    let fraction = if physics_time.scale() > 0.0 {
+        physics_steps.interpolation_fraction()
+    } else {
+        1.0
+    };
+
+    for (local, global, mut state, body_type) in query.iter_mut() {
+        if !body_type.copied().unwrap_or_default().can_have_velocity() {
+            continue;
+        }
+
+        let (translation, rotation) = state.interpolate(fraction);
+        state.rendered = set_bevy_transform(local, global, translation, rotation);
+    }
+}
+
+pub(crate) fn remove_interpolation_state(
+    mut commands: Commands<'_>,
+    removed: RemovedComponents<'_, TransformInterpolation>,
+) {
+    for entity in removed.iter() {
+        commands.entity(entity).remove::<InterpolationState>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_between_last_two_poses() {
+        let mut state = InterpolationState::new(Vec3::ZERO, Quat::IDENTITY);
+        state.push(Vec3::X * 2.0, Quat::from_rotation_z(1.0));
+
+        let (translation, rotation) = state.interpolate(0.25);
+
+        assert!(translation.abs_diff_eq(Vec3::X * 0.5, 0.0001));
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_z(0.25), 0.0001));
+    }
+
+    #[test]
+    fn reset_forgets_previous_poses() {
+        let mut state = InterpolationState::new(Vec3::ZERO, Quat::IDENTITY);
+        state.push(Vec3::X, Quat::IDENTITY);
+        state.reset(Vec3::Y, Quat::IDENTITY);
+
+        let (translation, _) = state.interpolate(0.5);
+
+        assert_eq!(translation, Vec3::Y);
+        assert!(state.is_rendered(&GlobalTransform::from_translation(Vec3::Y)));
+    }
+}
@@ -0,0 +1,182 @@
+use bevy::ecs::prelude::*;
+use bevy::math::prelude::*;
+use fnv::FnvHashMap;
+
+use heron_core::{Joint, JointKind};
+
+use crate::body;
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::nalgebra::Unit;
+#[cfg(dim3)]
+use crate::rapier::dynamics::RevoluteJoint;
+use crate::rapier::dynamics::{
+    BallJoint, FixedJoint, IslandManager, JointHandle, JointParams, JointSet, PrismaticJoint,
+    RigidBodySet,
+};
+use crate::rapier::math::Vector;
+
+pub(crate) type HandleMap = FnvHashMap<Entity, JointHandle>;
+
+pub(crate) fn create(
+    mut commands: Commands<'_>,
+    mut joints: ResMut<'_, JointSet>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut handles: ResMut<'_, HandleMap>,
+    body_handles: Res<'_, body::HandleMap>,
+    query: Query<'_, (Entity, &Joint), Without<JointHandle>>,
+) {
+    for (entity, joint) in query.iter() {
+        if let (Some(body1), Some(body2)) = (
+            body_handles.get(&joint.body1),
+            body_handles.get(&joint.body2),
+        ) {
+            let rotation1 = bodies.get(*body1).map(|body| body.position().rotation);
+            let rotation2 = bodies.get(*body2).map(|body| body.position().rotation);
+            let params = joint_params(
+                joint.kind,
+                rotation1.map_or(Quat::IDENTITY, IntoBevy::into_bevy),
+                rotation2.map_or(Quat::IDENTITY, IntoBevy::into_bevy),
+            );
+            let handle = joints.insert(&mut *bodies, *body1, *body2, params);
+            handles.insert(entity, handle);
+            commands.entity(entity).insert(handle);
+        }
+    }
+}
+
+pub(crate) fn remove_invalids_after_components_removed(
+    mut commands: Commands<'_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut joints: ResMut<'_, JointSet>,
+    joints_removed: RemovedComponents<'_, Joint>,
+) {
+    for entity in joints_removed.iter() {
+        if let Some(handle) = handles.remove(&entity) {
+            joints.remove(handle, &mut islands, &mut *bodies, true);
+            commands.entity(entity).remove::<JointHandle>();
+        }
+    }
+}
+
+pub(crate) fn remove_invalids_after_component_changed(
+    mut commands: Commands<'_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut joints: ResMut<'_, JointSet>,
+    changed: Query<'_, (Entity, &JointHandle), Changed<Joint>>,
+) {
+    for (entity, handle) in changed.iter() {
+        joints.remove(*handle, &mut islands, &mut *bodies, true);
+        commands.entity(entity).remove::<JointHandle>();
+        handles.remove(&entity);
+    }
+}
+
+/// Rapier removes the joints attached to a rigid body when that body is removed.
+///
+/// This system forgets about such joints, so that they get created again if the bodies are
+/// re-created (e.g. after one of their component has changed).
+pub(crate) fn remove_invalids_after_bodies_removed(
+    mut commands: Commands<'_>,
+    mut handles: ResMut<'_, HandleMap>,
+    joints: Res<'_, JointSet>,
+) {
+    handles.retain(|entity, handle| {
+        let is_valid = joints.get(*handle).is_some();
+        if !is_valid {
+            commands.entity(*entity).remove::<JointHandle>();
+        }
+        is_valid
+    });
+}
+
+/// Converts the joint kind into rapier joint parameters, given the current rotations of the bodies
+#[cfg_attr(dim2, allow(unused_variables))]
+fn joint_params(kind: JointKind, rotation1: Quat, rotation2: Quat) -> JointParams {
+    match kind {
+        JointKind::Fixed {
+            local_anchor1,
+            local_rotation1,
+            local_anchor2,
+            local_rotation2,
+        } => FixedJoint::new(
+            (local_anchor1, local_rotation1).into_rapier(),
+            (local_anchor2, local_rotation2).into_rapier(),
+        )
+        .into(),
+        JointKind::Ball {
+            local_anchor1,
+            local_anchor2,
+        } => BallJoint::new(local_anchor1.into_rapier(), local_anchor2.into_rapier()).into(),
+        #[cfg(dim2)]
+        JointKind::Revolute {
+            local_anchor1,
+            local_anchor2,
+            ..
+        } => BallJoint::new(local_anchor1.into_rapier(), local_anchor2.into_rapier()).into(),
+        #[cfg(dim3)]
+        JointKind::Revolute {
+            local_anchor1,
+            local_axis1,
+            local_anchor2,
+            local_axis2,
+        } => RevoluteJoint::new(
+            local_anchor1.into_rapier(),
+            unit_axis(local_axis1),
+            local_anchor2.into_rapier(),
+            unit_axis(local_axis2),
+        )
+        .into(),
+        #[cfg(dim2)]
+        JointKind::Prismatic {
+            local_anchor1,
+            local_axis1,
+            local_anchor2,
+            local_axis2,
+        } => PrismaticJoint::new(
+            local_anchor1.into_rapier(),
+            unit_axis(local_axis1),
+            local_anchor2.into_rapier(),
+            unit_axis(local_axis2),
+        )
+        .into(),
+        #[cfg(dim3)]
+        JointKind::Prismatic {
+            local_anchor1,
+            local_axis1,
+            local_anchor2,
+            local_axis2,
+        } => {
+            // The tangents must match in world-space, otherwise the bodies are rotated to align them
+            let tangent = orthogonal(rotation1 * local_axis1);
+            PrismaticJoint::new(
+                local_anchor1.into_rapier(),
+                unit_axis(local_axis1),
+                (rotation1.inverse() * tangent).into_rapier(),
+                local_anchor2.into_rapier(),
+                unit_axis(local_axis2),
+                (rotation2.inverse() * tangent).into_rapier(),
+            )
+            .into()
+        }
+    }
+}
+
+#[inline]
+fn unit_axis(axis: Vec3) -> Unit<Vector<f32>> {
+    Unit::new_normalize(axis.into_rapier())
+}
+
+/// Returns a vector orthogonal to the given axis, used as the tangent of 3d prismatic joints
+#[inline]
+#[cfg(dim3)]
+fn orthogonal(axis: Vec3) -> Vec3 {
+    if axis.x.abs() > 0.9 {
+        axis.cross(Vec3::Y)
+    } else {
+        axis.cross(Vec3::X)
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) use rapier2d as rapier;
 #[cfg(dim3)]
 pub(crate) use rapier3d as rapier;
 
-use heron_core::{CollisionEvent, PhysicsSystem};
+use heron_core::{BodySleep, BodyWake, CollisionEvent, ContactDetails, PhysicsSystem};
 
 use crate::rapier::dynamics::{
     CCDSolver, IntegrationParameters, IslandManager, JointSet, RigidBodySet,
@@ -27,10 +27,25 @@ use crate::rapier::pipeline::{PhysicsPipeline, QueryPipeline};
 
 mod acceleration;
 mod body;
+mod character;
+pub use heron_core::{CharacterController, CharacterControllerOutput, CharacterHit};
 pub mod convert;
+mod damping;
+mod hooks;
+pub use hooks::{ContactModification, PhysicsHooks, PhysicsHooksResource, SolverContact};
+mod impulse;
+mod interpolation;
+mod joint;
 mod pipeline;
 pub use pipeline::{PhysicsWorld, RayCastInfo, ShapeCastCollisionInfo, ShapeCastCollisionType};
 mod shape;
+#[cfg(feature = "serde")]
+mod snapshot;
+#[cfg(feature = "serde")]
+pub use snapshot::PhysicsSnapshot;
+mod sleeping;
+mod stepper;
+pub use stepper::PhysicsStepper;
 mod velocity;
 
 /// Plugin that enables collision detection and physics behavior, powered by rapier.
@@ -40,7 +55,10 @@ pub struct RapierPlugin;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
 enum InternalSystem {
+    BodyRemoval,
     TransformPropagation,
+    RecordPoses,
+    VelocityUpdate,
 }
 
 impl Plugin for RapierPlugin {
@@ -49,37 +67,102 @@ impl Plugin for RapierPlugin {
             .init_resource::<PhysicsPipeline>()
             .init_resource::<body::HandleMap>()
             .init_resource::<shape::HandleMap>()
+            .init_resource::<shape::ConvexDecompositions>()
+            .init_resource::<joint::HandleMap>()
             .init_resource::<IntegrationParameters>()
             .add_event::<CollisionEvent>()
+            .add_event::<ContactDetails>()
+            .add_event::<BodySleep>()
+            .add_event::<BodyWake>()
             .insert_resource(BroadPhase::new())
             .insert_resource(NarrowPhase::new())
             .insert_resource(RigidBodySet::new())
-            .insert_resource(QueryPipeline::new())
+            .insert_resource(empty_query_pipeline())
             .insert_resource(IslandManager::new())
             .insert_resource(ColliderSet::new())
             .insert_resource(JointSet::new())
             .insert_resource(CCDSolver::new())
+            .insert_resource(stepper::PhysicsStages::new())
             .stage("heron-physics", |schedule: &mut Schedule| {
-                schedule
-                    .add_stage("heron-remove", removal_stage())
-                    .add_stage("heron-update-rapier-world", update_rapier_world_stage())
-                    .add_stage("heron-create-new-bodies", body_update_stage())
-                    .add_stage("heron-create-new-colliders", create_collider_stage())
+                schedule.add_stage(
+                    "heron-update-physics-world",
+                    SystemStage::single_threaded()
+                        .with_system(stepper::update_physics_world.exclusive_system()),
+                )
             })
-            .add_system_set_to_stage(CoreStage::PostUpdate, step_systems());
+            .add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                step_systems()
+                    .with_run_criteria(heron_core::should_step.system())
+                    .with_system(
+                        pipeline::update_integration_parameters
+                            .system()
+                            .before(PhysicsSystem::Events),
+                    ),
+            )
+            .add_system_set_to_stage(CoreStage::PostUpdate, interpolation_systems());
     }
 }
 
+/// Add the stages that update the rapier world from the components, before the physics step
+fn add_physics_stages(schedule: &mut Schedule) -> &mut Schedule {
+    schedule
+        .add_stage("heron-remove", removal_stage())
+        .add_stage("heron-move-characters", move_characters_stage())
+        .add_stage("heron-update-rapier-world", update_rapier_world_stage())
+        .add_stage("heron-create-new-bodies", body_update_stage())
+        .add_stage("heron-create-new-colliders", create_collider_stage())
+        .add_stage("heron-create-new-joints", create_joint_stage())
+}
+
+/// Query pipeline of an empty physics world
+///
+/// It is updated once, because the intersection queries panic on a pipeline that was never updated
+fn empty_query_pipeline() -> QueryPipeline {
+    let mut pipeline = QueryPipeline::new();
+    pipeline.update(
+        &IslandManager::new(),
+        &RigidBodySet::new(),
+        &ColliderSet::new(),
+    );
+    pipeline
+}
+
 fn removal_stage() -> SystemStage {
     SystemStage::single_threaded()
-        .with_system(body::remove_invalids_after_components_removed.system())
+        .with_system(
+            body::remove_invalids_after_components_removed
+                .system()
+                .label(InternalSystem::BodyRemoval),
+        )
         .with_system(shape::remove_invalids_after_components_removed.system())
-        .with_system(body::remove_invalids_after_component_changed.system())
+        .with_system(
+            body::remove_invalids_after_component_changed
+                .system()
+                .label(InternalSystem::BodyRemoval),
+        )
         .with_system(shape::remove_invalids_after_component_changed.system())
+        .with_system(shape::remove_invalids_after_material_changed.system())
+        .with_system(shape::remove_invalids_after_hooks_changed.system())
+        .with_system(joint::remove_invalids_after_components_removed.system())
+        .with_system(joint::remove_invalids_after_component_changed.system())
+        .with_system(
+            joint::remove_invalids_after_bodies_removed
+                .system()
+                .after(InternalSystem::BodyRemoval),
+        )
 }
 
+fn move_characters_stage() -> SystemStage {
+    SystemStage::single_threaded().with_system(character::move_characters.system())
+}
+
+/// Systems applying the components to the rapier world
+///
+/// The stage is single-threaded, because the order in which the bodies are modified changes the
+/// order in which rapier processes them, and the simulation wouldn't be deterministic otherwise.
 fn update_rapier_world_stage() -> SystemStage {
-    SystemStage::parallel()
+    SystemStage::single_threaded()
         .with_system(
             bevy::transform::transform_propagate_system::transform_propagate_system
                 .system()
@@ -90,8 +173,28 @@ fn update_rapier_world_stage() -> SystemStage {
                 .system()
                 .after(InternalSystem::TransformPropagation),
         )
-        .with_system(velocity::update_rapier_velocity.system())
-        .with_system(acceleration::update_rapier_force_and_torque.system())
+        .with_system(
+            velocity::update_rapier_velocity
+                .system()
+                .label(InternalSystem::VelocityUpdate),
+        )
+        .with_system(
+            impulse::apply_impulses
+                .system()
+                .after(InternalSystem::VelocityUpdate),
+        )
+        .with_system(damping::update_rapier_damping.system())
+        .with_system(damping::reset_rapier_damping.system())
+        .with_system(body::enable_ccd.system())
+        .with_system(body::disable_ccd.system())
+        .with_system(body::update_gravity_scale.system())
+        .with_system(body::reset_gravity_scale.system())
+        .with_system(body::update_dominance.system())
+        .with_system(body::reset_dominance.system())
+        .with_system(sleeping::force_sleep.system())
+        .with_system(sleeping::force_wake_up.system())
+        .with_system(sleeping::update_sleep_threshold.system())
+        .with_system(sleeping::reset_sleep_threshold.system())
         .with_system(shape::update_position.system())
         .with_system(shape::update_collision_groups.system())
         .with_system(shape::update_sensor_flag.system())
@@ -107,30 +210,48 @@ fn create_collider_stage() -> SystemStage {
     SystemStage::single_threaded().with_system(shape::create.system())
 }
 
+fn create_joint_stage() -> SystemStage {
+    SystemStage::single_threaded().with_system(joint::create.system())
+}
+
+/// Systems of the physics step
+///
+/// The integration parameters must be up to date before the systems run.
 fn step_systems() -> SystemSet {
     SystemSet::new()
-        .with_run_criteria(heron_core::should_run.system())
+        .with_system(pipeline::step.system().label(PhysicsSystem::Events))
         .with_system(
-            velocity::apply_velocity_to_kinematic_bodies
+            body::update_bevy_transform
                 .system()
-                .before(PhysicsSystem::Events),
+                .label(PhysicsSystem::TransformUpdate)
+                .after(PhysicsSystem::Events),
         )
         .with_system(
-            pipeline::update_integration_parameters
+            velocity::update_velocity_component
                 .system()
-                .before(PhysicsSystem::Events),
+                .label(PhysicsSystem::VelocityUpdate)
+                .after(PhysicsSystem::Events),
         )
-        .with_system(pipeline::step.system().label(PhysicsSystem::Events))
         .with_system(
-            body::update_bevy_transform
+            sleeping::update_sleeping_component
                 .system()
-                .label(PhysicsSystem::TransformUpdate)
                 .after(PhysicsSystem::Events),
         )
         .with_system(
-            velocity::update_velocity_component
+            interpolation::record_poses
                 .system()
-                .label(PhysicsSystem::VelocityUpdate)
+                .label(InternalSystem::RecordPoses)
                 .after(PhysicsSystem::Events),
         )
 }
+
+/// Systems that run at every frame, including the frames without physics step
+fn interpolation_systems() -> SystemSet {
+    SystemSet::new()
+        .with_system(
+            interpolation::interpolate_transforms
+                .system()
+                .after(InternalSystem::RecordPoses),
+        )
+        .with_system(interpolation::remove_interpolation_state.system())
+}
@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
 use bevy::app::Events;
 use bevy::ecs::prelude::*;
 use bevy::log::prelude::*;
@@ -6,11 +9,13 @@ use bevy::math::Vec3;
 use crossbeam::channel::{Receiver, Sender};
 
 use heron_core::{
-    CollisionData, CollisionEvent, CollisionLayers, CollisionShape, Gravity, PhysicsStepDuration,
-    PhysicsSteps, PhysicsTime,
+    CollisionData, CollisionEvent, CollisionLayers, CollisionShape, ContactDetails, ContactPoint,
+    Gravity, PhysicsStepDuration, PhysicsSteps, PhysicsTime,
 };
 
+use crate::acceleration::Forces;
 use crate::convert::{IntoBevy, IntoRapier};
+use crate::hooks::StepHooks;
 use crate::rapier::dynamics::{
     CCDSolver, IntegrationParameters, IslandManager, JointSet, RigidBodySet,
 };
@@ -18,9 +23,11 @@ use crate::rapier::geometry::{
     BroadPhase, ColliderHandle, ColliderSet, ContactEvent, InteractionGroups, IntersectionEvent,
     NarrowPhase,
 };
-use crate::rapier::parry::query::{Ray, TOIStatus};
+use crate::rapier::math::{Isometry, Point};
+use crate::rapier::parry::query::{self, Ray, TOIStatus};
 use crate::rapier::pipeline::{EventHandler, PhysicsPipeline, QueryPipeline};
 use crate::shape::ColliderFactory;
+use crate::velocity::KinematicVelocities;
 
 use bevy::ecs::system::SystemParam;
 
@@ -129,7 +136,12 @@ mod physics_world {
                         .colliders
                         .get(collider_handle)
                         .map(|collider| Entity::from_bits(collider.user_data as u64))?,
-                    normal: intersection.normal.into_bevy(),
+                    // In 2d, parry doesn't normalize the normals of segments and polygon edges
+                    normal: intersection
+                        .normal
+                        .into_bevy()
+                        .try_normalize()
+                        .unwrap_or(Vec3::ZERO),
                 })
             })?
         }
@@ -198,6 +210,65 @@ mod physics_world {
             )
         }
 
+        /// Returns the entity of the collider
+        #[allow(clippy::cast_possible_truncation)]
+        pub(crate) fn collider_entity(&self, handle: ColliderHandle) -> Option<Entity> {
+            self.colliders
+                .get(handle)
+                .map(|collider| Entity::from_bits(collider.user_data as u64))
+        }
+
+        /// Returns the contacts of the given shape with the collision shapes of the world that it
+        /// intersects
+        ///
+        /// The normals point toward the given shape, in world-space.
+        #[allow(clippy::cast_possible_truncation)]
+        pub(crate) fn contacts_with_filter(
+            &self,
+            shape: &CollisionShape,
+            position: Vec3,
+            rotation: Quat,
+            layers: CollisionLayers,
+            filter: &dyn Fn(Entity) -> bool,
+        ) -> Vec<ShapeContact> {
+            let collider = shape.collider_builder().build();
+            let shape_pos: Isometry<f32> = (position, rotation).into_rapier();
+            let groups = InteractionGroups {
+                memberships: layers.groups_bits(),
+                filter: layers.masks_bits(),
+            };
+
+            let mut contacts = Vec::new();
+            self.query_pipeline.intersections_with_shape(
+                &*self.colliders,
+                &shape_pos,
+                collider.shape(),
+                groups,
+                Some(&|handle: ColliderHandle| self.collider_entity(handle).map_or(false, filter)),
+                |handle| {
+                    let other = match self.colliders.get(handle) {
+                        Some(other) => other,
+                        None => return true,
+                    };
+                    if let Ok(Some(contact)) = query::contact(
+                        other.position(),
+                        other.shape(),
+                        &shape_pos,
+                        collider.shape(),
+                        0.0,
+                    ) {
+                        contacts.push(ShapeContact {
+                            entity: Entity::from_bits(other.user_data as u64),
+                            normal: (*contact.normal1).into_bevy(),
+                            depth: -contact.dist,
+                        });
+                    }
+                    true
+                },
+            );
+            contacts
+        }
+
         #[must_use]
         #[allow(clippy::cast_possible_truncation)]
         fn shape_cast_internal(
@@ -280,6 +351,17 @@ mod physics_world {
     }
 }
 
+/// Contact of a shape with a collision shape of the world
+#[derive(Clone, Debug)]
+pub(crate) struct ShapeContact {
+    /// The collision shape entity that is touched
+    pub(crate) entity: Entity,
+    /// The normal of the contact, pointing from the collision shape toward the shape, in world-space
+    pub(crate) normal: Vec3,
+    /// The penetration depth, negative if the shapes are separated
+    pub(crate) depth: f32,
+}
+
 /// The result of a [`PhysicsWorld::ray_cast`] operation
 #[derive(Clone, Debug)]
 pub struct RayCastInfo {
@@ -338,14 +420,22 @@ pub(crate) fn update_integration_parameters(
     ) || physics_steps.is_changed()
         || physics_time.is_changed()
     {
-        integration_parameters.dt = physics_steps
-            .duration()
-            .exact(bevy_time.delta())
-            .as_secs_f32()
-            * physics_time.scale();
+        integration_parameters.dt =
+            substep_duration(&physics_steps, &physics_time, bevy_time.delta());
     }
 }
 
+/// Time (in seconds) simulated by each substep, given the delta time of the frame
+pub(crate) fn substep_duration(
+    physics_steps: &PhysicsSteps,
+    physics_time: &PhysicsTime,
+    delta_time: Duration,
+) -> f32 {
+    #[allow(clippy::cast_precision_loss)]
+    let substeps = physics_steps.substeps() as f32;
+    physics_steps.duration().exact(delta_time).as_secs_f32() * physics_time.scale() / substeps
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn step(
     mut pipeline: ResMut<'_, PhysicsPipeline>,
@@ -360,29 +450,45 @@ pub(crate) fn step(
     mut joints: ResMut<'_, JointSet>,
     mut ccd_solver: ResMut<'_, CCDSolver>,
     event_manager: Local<'_, EventManager>,
-    mut events: ResMut<'_, Events<CollisionEvent>>,
+    (mut events, mut contact_details): (
+        ResMut<'_, Events<CollisionEvent>>,
+        ResMut<'_, Events<ContactDetails>>,
+    ),
+    hooks: StepHooks<'_>,
+    (forces, kinematic_velocities): (Forces<'_>, KinematicVelocities<'_>),
+    physics_steps: Res<'_, PhysicsSteps>,
 ) {
     let gravity = Vec3::from(*gravity).into_rapier();
 
-    // Step the physics simulation
-    pipeline.step(
-        &gravity,
-        &integration_parameters,
-        &mut islands,
-        &mut broad_phase,
-        &mut narrow_phase,
-        &mut bodies,
-        &mut colliders,
-        &mut joints,
-        &mut ccd_solver,
-        &(),
-        &*event_manager,
-    );
+    // Step the physics simulation, once per substep
+    for _ in 0..physics_steps.substeps() {
+        forces.apply(&mut bodies);
+        kinematic_velocities.apply(&mut bodies);
+        pipeline.step(
+            &gravity,
+            &integration_parameters,
+            &mut islands,
+            &mut broad_phase,
+            &mut narrow_phase,
+            &mut bodies,
+            &mut colliders,
+            &mut joints,
+            &mut ccd_solver,
+            &hooks,
+            &*event_manager,
+        );
+    }
 
     // Update the query pipleine
     query_pipeline.update(&islands, &bodies, &colliders);
 
-    event_manager.fire_events(&bodies, &colliders, &mut events);
+    event_manager.fire_events(
+        &bodies,
+        &colliders,
+        &narrow_phase,
+        &mut events,
+        &mut contact_details,
+    );
 }
 
 pub(crate) struct EventManager {
@@ -424,13 +530,20 @@ impl EventManager {
         &self,
         bodies: &RigidBodySet,
         colliders: &ColliderSet,
+        narrow_phase: &NarrowPhase,
         events: &mut Events<CollisionEvent>,
+        contact_details: &mut Events<ContactDetails>,
     ) {
         while let Ok(event) = self.contact_recv.try_recv() {
             match event {
                 ContactEvent::Started(h1, h2) => {
                     if let Some((d1, d2)) = Self::data(bodies, colliders, h1, h2) {
                         events.send(CollisionEvent::Started(d1, d2));
+                        if let Some(details) =
+                            Self::details(colliders, narrow_phase, h1, h2, d1, d2)
+                        {
+                            contact_details.send(details);
+                        }
                     }
                 }
                 ContactEvent::Stopped(h1, h2) => {
@@ -495,6 +608,72 @@ impl EventManager {
             None
         }
     }
+
+    /// Read the contact manifolds of the pair, after the step so that the impulses are known
+    #[allow(clippy::cast_possible_truncation)]
+    fn details(
+        colliders: &ColliderSet,
+        narrow_phase: &NarrowPhase,
+        h1: ColliderHandle,
+        h2: ColliderHandle,
+        d1: CollisionData,
+        d2: CollisionData,
+    ) -> Option<ContactDetails> {
+        let pair = narrow_phase.contact_pair(h1, h2)?;
+        let collider1 = colliders.get(pair.collider1)?;
+        let collider2 = colliders.get(pair.collider2)?;
+
+        // The event data is sorted by entity, which may not be the order of the contact pair
+        let is_swapped =
+            Entity::from_bits(collider1.user_data as u64) != d1.collision_shape_entity();
+
+        let mut points = Vec::new();
+        for manifold in &pair.manifolds {
+            let position1 =
+                collider1.position() * manifold.subshape_pos1.unwrap_or_else(Isometry::identity);
+            let position2 =
+                collider2.position() * manifold.subshape_pos2.unwrap_or_else(Isometry::identity);
+
+            // Each manifold has its own normal (for instance, one per triangle of a mesh)
+            let normal: Vec3 = (position1 * manifold.local_n1).into_bevy();
+            let normal = if is_swapped { -normal } else { normal };
+
+            points.extend(manifold.points.iter().map(|contact| {
+                let point1 = point_into_bevy(position1 * contact.local_p1);
+                let point2 = point_into_bevy(position2 * contact.local_p2);
+                ContactPoint {
+                    point1: if is_swapped { point2 } else { point1 },
+                    point2: if is_swapped { point1 } else { point2 },
+                    normal,
+                    penetration_depth: -contact.dist,
+                    impulse: contact.data.impulse,
+                }
+            }));
+        }
+
+        let normal = points
+            .iter()
+            .max_by(|a, b| {
+                a.penetration_depth
+                    .partial_cmp(&b.penetration_depth)
+                    .unwrap_or(Ordering::Equal)
+            })?
+            .normal;
+        Some(ContactDetails::new(d1, d2, normal, points))
+    }
+}
+
+#[inline]
+#[cfg(dim2)]
+pub(crate) fn point_into_bevy(point: Point<f32>) -> Vec3 {
+    let point: bevy::math::Vec2 = point.into_bevy();
+    point.extend(0.0)
+}
+
+#[inline]
+#[cfg(dim3)]
+pub(crate) fn point_into_bevy(point: Point<f32>) -> Vec3 {
+    point.into_bevy()
 }
 
 #[cfg(test)]
@@ -517,6 +696,7 @@ mod tests {
     struct TestContext {
         bodies: RigidBodySet,
         colliders: ColliderSet,
+        narrow_phase: NarrowPhase,
         rb_entity_1: Entity,
         rb_entity_2: Entity,
         collider_entity_1: Entity,
@@ -568,6 +748,7 @@ mod tests {
             Self {
                 bodies,
                 colliders,
+                narrow_phase: NarrowPhase::new(),
                 rb_entity_1,
                 rb_entity_2,
                 collider_entity_1,
@@ -591,7 +772,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).copied().collect();
 
         assert_eq!(events.len(), 1);
@@ -614,7 +801,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).copied().collect();
 
         assert_eq!(events.len(), 1);
@@ -641,7 +834,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).copied().collect();
 
         assert_eq!(events.len(), 1);
@@ -668,7 +867,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).copied().collect();
 
         assert_eq!(events.len(), 1);
@@ -691,7 +896,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         assert_eq!(
             events
                 .get_reader()
@@ -714,7 +925,13 @@ mod tests {
             .unwrap();
 
         let mut events = Events::<CollisionEvent>::default();
-        manager.fire_events(&context.bodies, &context.colliders, &mut events);
+        manager.fire_events(
+            &context.bodies,
+            &context.colliders,
+            &context.narrow_phase,
+            &mut events,
+            &mut Events::default(),
+        );
         assert_eq!(
             events
                 .get_reader()
@@ -1,15 +1,21 @@
 use bevy::prelude::*;
 use fnv::FnvHashMap;
 
-use heron_core::{CollisionLayers, CollisionShape, PhysicMaterial, RigidBody, SensorShape};
+use heron_core::{
+    ActivePhysicsHooks, CollisionLayers, CollisionShape, ConvexDecompositionParameters,
+    MassProperties, OneWayPlatform, PhysicMaterial, RigidBody, SensorShape, SurfaceVelocity,
+};
 
 use crate::convert::IntoRapier;
+use crate::hooks;
+use crate::nalgebra::Unit;
 use crate::rapier::dynamics::{IslandManager, RigidBodyHandle, RigidBodySet};
 use crate::rapier::geometry::{
-    Collider, ColliderBuilder, ColliderHandle, ColliderSet, InteractionGroups,
+    Collider, ColliderBuilder, ColliderHandle, ColliderSet, InteractionGroups, SharedShape,
 };
-use crate::rapier::math::Point;
-use crate::rapier::pipeline::ActiveEvents;
+use crate::rapier::math::{Isometry, Point};
+use crate::rapier::parry::transformation::vhacd::VHACD;
+use crate::rapier::pipeline::{ActiveEvents, ActiveHooks};
 
 pub(crate) type HandleMap = FnvHashMap<Entity, ColliderHandle>;
 
@@ -18,7 +24,16 @@ pub(crate) fn create(
     mut bodies: ResMut<'_, RigidBodySet>,
     mut colliders: ResMut<'_, ColliderSet>,
     mut handles: ResMut<'_, HandleMap>,
-    rigid_bodies: Query<'_, (&RigidBody, &RigidBodyHandle, Option<&PhysicMaterial>)>,
+    mut decompositions: ResMut<'_, ConvexDecompositions>,
+    rigid_bodies: Query<
+        '_,
+        (
+            &RigidBody,
+            &RigidBodyHandle,
+            Option<&PhysicMaterial>,
+            Option<&MassProperties>,
+        ),
+    >,
     collision_shapes: Query<
         '_,
         (
@@ -28,45 +43,74 @@ pub(crate) fn create(
             Option<&Transform>,
             Option<&CollisionLayers>,
             Option<&SensorShape>,
+            Option<&PhysicMaterial>,
+            Option<&ActivePhysicsHooks>,
+            Option<&OneWayPlatform>,
+            Option<&SurfaceVelocity>,
         ),
         Without<ColliderHandle>,
     >,
 ) {
-    for (entity, shape, parent, transform, layers, sensor_flag) in collision_shapes.iter() {
-        let collider = if let Ok((body, rigid_body_handle, material)) = rigid_bodies.get(entity) {
-            Some((
-                shape.build(
-                    entity,
-                    sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
-                    None,
-                    layers,
-                ),
-                rigid_body_handle,
-            ))
-        } else if let Some((body, rigid_body_handle, material)) =
-            parent.and_then(|p| rigid_bodies.get(p.0).ok())
-        {
-            Some((
-                shape.build(
-                    entity,
-                    sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
+    for (
+        entity,
+        shape,
+        parent,
+        transform,
+        layers,
+        sensor_flag,
+        shape_material,
+        active_hooks,
+        one_way_platform,
+        surface_velocity,
+    ) in collision_shapes.iter()
+    {
+        let (body, rigid_body_handle, material, mass_properties, transform) =
+            if let Ok((body, handle, material, mass_properties)) = rigid_bodies.get(entity) {
+                (body, handle, material, mass_properties, None)
+            } else if let Some((body, handle, material, mass_properties)) =
+                parent.and_then(|p| rigid_bodies.get(p.0).ok())
+            {
+                // The material of a child collision shape overrides the one of the rigid body
+                (
+                    body,
+                    handle,
+                    shape_material.or(material),
+                    mass_properties,
                     transform,
-                    layers,
-                ),
-                rigid_body_handle,
-            ))
+                )
+            } else {
+                continue;
+            };
+
+        // The mass properties of the body override the ones derived from the colliders
+        let material = if mass_properties.is_some() {
+            Some(PhysicMaterial {
+                density: 0.0,
+                ..material.copied().unwrap_or_default()
+            })
         } else {
-            None
+            material.copied()
         };
 
-        if let Some((collider, rigid_body_handle)) = collider {
-            let handle = colliders.insert_with_parent(collider, *rigid_body_handle, &mut bodies);
-            commands.entity(entity).insert(handle);
-            handles.insert(entity, handle);
-        }
+        let collider = shape.build(
+            &mut decompositions,
+            entity,
+            sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
+            material.as_ref(),
+            transform,
+            layers,
+            hooks::active_hooks(
+                active_hooks,
+                one_way_platform.is_some() || surface_velocity.is_some(),
+            ),
+        );
+
+        let handle = colliders.insert_with_parent(collider, *rigid_body_handle, &mut bodies);
+        commands.entity(entity).insert(handle);
+        handles.insert(entity, handle);
     }
+
+    decompositions.remove_unused();
 }
 
 pub(crate) fn update_position(
@@ -175,27 +219,107 @@ pub(crate) fn remove_invalids_after_component_changed(
     }
 }
 
+/// Remove the colliders of child collision shapes whose [`PhysicMaterial`] changed or was
+/// removed, so that they are re-created with the new material, without re-creating the rigid body
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn remove_invalids_after_material_changed(
+    mut commands: Commands<'_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut colliders: ResMut<'_, ColliderSet>,
+    changed: Query<'_, (Entity, &ColliderHandle), (Changed<PhysicMaterial>, Without<RigidBody>)>,
+    collider_handles: Query<'_, &ColliderHandle, Without<RigidBody>>,
+    materials_removed: RemovedComponents<'_, PhysicMaterial>,
+) {
+    let removed = materials_removed.iter().filter_map(|entity| {
+        collider_handles
+            .get(entity)
+            .ok()
+            .map(|handle| (entity, handle))
+    });
+
+    for (entity, handle) in changed.iter().chain(removed) {
+        colliders.remove(*handle, &mut islands, &mut bodies, true);
+        commands.entity(entity).remove::<ColliderHandle>();
+        handles.remove(&entity);
+    }
+}
+
+/// Remove the colliders whose [`ActivePhysicsHooks`] changed, or which gained or lost a
+/// [`OneWayPlatform`] or a [`SurfaceVelocity`], so that they are re-created with the new hooks flags
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn remove_invalids_after_hooks_changed(
+    mut commands: Commands<'_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut colliders: ResMut<'_, ColliderSet>,
+    changed: Query<
+        '_,
+        (Entity, &ColliderHandle),
+        Or<(
+            Changed<ActivePhysicsHooks>,
+            Added<OneWayPlatform>,
+            Added<SurfaceVelocity>,
+        )>,
+    >,
+    collider_handles: Query<'_, &ColliderHandle>,
+    hooks_removed: RemovedComponents<'_, ActivePhysicsHooks>,
+    platforms_removed: RemovedComponents<'_, OneWayPlatform>,
+    surface_velocities_removed: RemovedComponents<'_, SurfaceVelocity>,
+) {
+    let removed = hooks_removed
+        .iter()
+        .chain(platforms_removed.iter())
+        .chain(surface_velocities_removed.iter())
+        .filter_map(|entity| {
+            collider_handles
+                .get(entity)
+                .ok()
+                .map(|handle| (entity, handle))
+        });
+
+    for (entity, handle) in changed.iter().chain(removed) {
+        colliders.remove(*handle, &mut islands, &mut bodies, true);
+        commands.entity(entity).remove::<ColliderHandle>();
+        handles.remove(&entity);
+    }
+}
+
 pub(crate) trait ColliderFactory {
-    fn collider_builder(&self) -> ColliderBuilder;
+    fn collider_builder(&self) -> ColliderBuilder {
+        self.cached_collider_builder(&mut ConvexDecompositions::default())
+    }
 
+    /// Same as `collider_builder`, but reuses the convex decompositions already computed
+    fn cached_collider_builder(&self, decompositions: &mut ConvexDecompositions)
+        -> ColliderBuilder;
+
+    #[allow(clippy::too_many_arguments)]
     fn build(
         &self,
+        decompositions: &mut ConvexDecompositions,
         entity: Entity,
         is_sensor: bool,
         material: Option<&PhysicMaterial>,
         transform: Option<&Transform>,
         layers: Option<&CollisionLayers>,
+        active_hooks: ActiveHooks,
     ) -> Collider {
         let mut builder = self
-            .collider_builder()
+            .cached_collider_builder(decompositions)
             .user_data(entity.to_bits().into())
-            .sensor(is_sensor);
+            .sensor(is_sensor)
+            .active_hooks(active_hooks);
 
         if let Some(material) = material {
             builder = builder
                 .restitution(material.restitution)
                 .density(material.density)
-                .friction(material.friction);
+                .friction(material.friction)
+                .friction_combine_rule(material.friction_combine_rule.into_rapier())
+                .restitution_combine_rule(material.restitution_combine_rule.into_rapier());
         }
 
         if let Some(transform) = transform {
@@ -211,7 +335,10 @@ pub(crate) trait ColliderFactory {
 }
 
 impl ColliderFactory for CollisionShape {
-    fn collider_builder(&self) -> ColliderBuilder {
+    fn cached_collider_builder(
+        &self,
+        decompositions: &mut ConvexDecompositions,
+    ) -> ColliderBuilder {
         match self {
             CollisionShape::Sphere { radius } => ColliderBuilder::ball(*radius),
             CollisionShape::Capsule {
@@ -227,6 +354,45 @@ impl ColliderFactory for CollisionShape {
                 border_radius,
             } => convex_hull_builder(points.as_slice(), *border_radius),
             CollisionShape::HeightField { size, heights } => heightfield_builder(*size, &heights),
+            CollisionShape::TriMesh { vertices, indices } => {
+                ColliderBuilder::trimesh(vertices.as_slice().into_rapier(), indices.clone())
+            }
+            CollisionShape::Polyline { vertices, indices } => {
+                ColliderBuilder::polyline(vertices.as_slice().into_rapier(), indices.clone())
+            }
+            CollisionShape::Compound(shapes) => compound_builder(decompositions, shapes),
+            CollisionShape::ConvexDecomposition {
+                vertices,
+                indices,
+                parameters,
+            } => compound_or_point(
+                decompositions
+                    .parts(vertices, indices, *parameters)
+                    .into_iter()
+                    .map(|shape| (Isometry::identity(), shape))
+                    .collect(),
+            ),
+            CollisionShape::Segment { a, b } => {
+                ColliderBuilder::segment((*a).into_rapier(), (*b).into_rapier())
+            }
+            CollisionShape::Triangle { a, b, c } => ColliderBuilder::triangle(
+                (*a).into_rapier(),
+                (*b).into_rapier(),
+                (*c).into_rapier(),
+            ),
+            CollisionShape::HalfSpace { outward_normal } => {
+                ColliderBuilder::halfspace(Unit::new_normalize((*outward_normal).into_rapier()))
+            }
+            #[cfg(dim3)]
+            CollisionShape::Cylinder {
+                half_height,
+                radius,
+            } => ColliderBuilder::cylinder(*half_height, *radius),
+            #[cfg(dim3)]
+            CollisionShape::Cone {
+                half_height,
+                radius,
+            } => ColliderBuilder::cone(*half_height, *radius),
         }
         // General all types of collision events
         .active_events(ActiveEvents::all())
@@ -272,6 +438,162 @@ fn convex_hull_builder(points: &[Vec3], border_radius: Option<f32>) -> ColliderB
     )
 }
 
+#[inline]
+fn compound_builder(
+    decompositions: &mut ConvexDecompositions,
+    shapes: &[(Transform, CollisionShape)],
+) -> ColliderBuilder {
+    let mut parts = Vec::new();
+    add_compound_parts(decompositions, &mut parts, Isometry::identity(), shapes);
+
+    compound_or_point(parts)
+}
+
+/// Returns a compound of the parts, or a point if there is no part (as compounds cannot be empty)
+fn compound_or_point(parts: Vec<(Isometry<f32>, SharedShape)>) -> ColliderBuilder {
+    if parts.is_empty() {
+        warn!("The compound collision shape has no supported shape, a point is used instead");
+        ColliderBuilder::ball(0.0)
+    } else {
+        ColliderBuilder::compound(parts)
+    }
+}
+
+/// Add the shapes of a compound to the parts, flattening the nested compounds
+///
+/// The composite shapes cannot be part of a compound, so they are ignored.
+fn add_compound_parts(
+    decompositions: &mut ConvexDecompositions,
+    parts: &mut Vec<(Isometry<f32>, SharedShape)>,
+    position: Isometry<f32>,
+    shapes: &[(Transform, CollisionShape)],
+) {
+    for (transform, shape) in shapes {
+        let position = position * (transform.translation, transform.rotation).into_rapier();
+
+        if let CollisionShape::Compound(shapes) = shape {
+            add_compound_parts(decompositions, parts, position, shapes);
+            continue;
+        }
+
+        let shared_shape = shape.cached_collider_builder(decompositions).shape;
+        if let Some(compound) = shared_shape.as_compound() {
+            parts.extend(
+                compound
+                    .shapes()
+                    .iter()
+                    .map(|(delta, shape)| (position * delta, shape.clone())),
+            );
+        } else if shared_shape.as_composite_shape().is_some() {
+            warn!(
+                "{:?} is not supported in a compound collision shape and is ignored",
+                shared_shape.shape_type()
+            );
+        } else {
+            parts.push((position, shared_shape));
+        }
+    }
+}
+
+/// Convex parts of the meshes of the [`CollisionShape::ConvexDecomposition`] shapes
+///
+/// The decomposition is slow, so the parts are kept as long as a collider uses them, and are reused
+/// by the colliders created for the same mesh (for instance when a rigid body is re-created).
+#[derive(Default)]
+pub(crate) struct ConvexDecompositions(FnvHashMap<DecompositionKey, Vec<SharedShape>>);
+
+/// Mesh and parameters of a decomposition, with the floats compared by their bits
+#[derive(PartialEq, Eq, Hash)]
+struct DecompositionKey {
+    vertices: Vec<[u32; 3]>,
+    indices: Vec<[u32; 3]>,
+    resolution: u32,
+    concavity: u32,
+}
+
+impl ConvexDecompositions {
+    /// Returns the convex parts of the mesh, decomposing it if it isn't already
+    fn parts(
+        &mut self,
+        vertices: &[Vec3],
+        indices: &[[u32; 3]],
+        parameters: ConvexDecompositionParameters,
+    ) -> Vec<SharedShape> {
+        let key = DecompositionKey {
+            vertices: vertices
+                .iter()
+                .map(|v| [v.x.to_bits(), v.y.to_bits(), v.z.to_bits()])
+                .collect(),
+            indices: indices.to_vec(),
+            resolution: parameters.resolution,
+            concavity: parameters.concavity.to_bits(),
+        };
+
+        self.0
+            .entry(key)
+            .or_insert_with(|| decompose(vertices, indices, parameters))
+            .clone()
+    }
+
+    /// Forget the decompositions that are not used by any collider anymore
+    fn remove_unused(&mut self) {
+        self.0.retain(|_, parts| {
+            parts
+                .iter()
+                .any(|part| std::sync::Arc::strong_count(&part.0) > 1)
+        });
+    }
+}
+
+/// Decompose the mesh into convex parts
+///
+/// The decomposition of parry is not used directly, because it panics when a part has an empty
+/// convex hull, and because it creates the 2d polygons in clockwise order (with inverted normals).
+fn decompose(
+    vertices: &[Vec3],
+    indices: &[[u32; 3]],
+    parameters: ConvexDecompositionParameters,
+) -> Vec<SharedShape> {
+    let points: Vec<Point<f32>> = vertices.into_rapier();
+    #[cfg(dim2)]
+    let indices = outline(indices);
+    let decomposition = VHACD::decompose(&parameters.into_rapier(), &points, &indices, true);
+
+    #[cfg(dim2)]
+    let parts = decomposition
+        .compute_exact_convex_hulls(&points, &indices)
+        .into_iter()
+        .filter_map(|hull| SharedShape::convex_hull(&hull));
+
+    #[cfg(dim3)]
+    let parts = decomposition
+        .compute_exact_convex_hulls(&points, &indices)
+        .into_iter()
+        .filter(|(_, indices)| !indices.is_empty())
+        .filter_map(|(hull, indices)| SharedShape::convex_mesh(hull, &indices));
+
+    parts.collect()
+}
+
+/// Returns the segments that belong to only one triangle, which are the outline of the triangles
+///
+/// The 2d decomposition works on such outline, rather than on triangles.
+#[cfg(dim2)]
+fn outline(triangles: &[[u32; 3]]) -> Vec<[u32; 2]> {
+    let segments = triangles
+        .iter()
+        .flat_map(|[a, b, c]| vec![[*a, *b], [*b, *c], [*c, *a]]);
+
+    let mut counts = FnvHashMap::<[u32; 2], usize>::default();
+    for [a, b] in segments.clone() {
+        *counts.entry([a.min(b), a.max(b)]).or_default() += 1;
+    }
+
+    segments
+        .filter(|&[a, b]| counts.get(&[a.min(b), a.max(b)]) == Some(&1))
+        .collect()
+}
+
 #[inline]
 #[cfg(dim2)]
 #[allow(clippy::cast_precision_loss)]
@@ -394,4 +716,323 @@ mod tests {
             assert_eq!(field.cell_width(), 1.0);
         }
     }
+
+    #[test]
+    fn build_trimesh() {
+        let collider = CollisionShape::TriMesh {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE],
+            indices: vec![[0, 1, 2], [1, 3, 2]],
+        }
+        .collider_builder()
+        .build();
+
+        let trimesh = collider
+            .shape()
+            .as_trimesh()
+            .expect("Created shape was not a triangle mesh");
+
+        assert_eq!(trimesh.vertices().len(), 4);
+        assert_eq!(trimesh.indices(), &[[0, 1, 2], [1, 3, 2]]);
+    }
+
+    #[test]
+    fn build_polyline() {
+        let collider = CollisionShape::Polyline {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::ONE],
+            indices: None,
+        }
+        .collider_builder()
+        .build();
+
+        let polyline = collider
+            .shape()
+            .as_polyline()
+            .expect("Created shape was not a polyline");
+
+        assert_eq!(polyline.vertices().len(), 3);
+        assert_eq!(polyline.num_segments(), 2);
+    }
+
+    #[test]
+    fn build_compound() {
+        let collider = CollisionShape::Compound(vec![
+            (Transform::default(), CollisionShape::Sphere { radius: 1.0 }),
+            (
+                Transform::from_xyz(2.0, 0.0, 0.0),
+                CollisionShape::Cuboid {
+                    half_extends: Vec3::ONE,
+                    border_radius: None,
+                },
+            ),
+        ])
+        .collider_builder()
+        .build();
+
+        let compound = collider
+            .shape()
+            .as_compound()
+            .expect("Created shape was not a compound");
+
+        assert_eq!(compound.shapes().len(), 2);
+        assert!(compound.shapes()[0].1.as_ball().is_some());
+        assert!(compound.shapes()[1].1.as_cuboid().is_some());
+        assert_eq!(compound.shapes()[1].0.translation.x, 2.0);
+    }
+
+    #[test]
+    fn build_empty_compound() {
+        let collider = CollisionShape::Compound(Vec::new())
+            .collider_builder()
+            .build();
+
+        assert!(collider.shape().as_ball().is_some());
+    }
+
+    #[test]
+    fn build_nested_compound() {
+        let collider = CollisionShape::Compound(vec![
+            (Transform::default(), CollisionShape::Sphere { radius: 1.0 }),
+            (
+                Transform::from_xyz(2.0, 0.0, 0.0),
+                CollisionShape::Compound(vec![
+                    (
+                        Transform::from_xyz(0.0, 3.0, 0.0),
+                        CollisionShape::Sphere { radius: 1.0 },
+                    ),
+                    (
+                        Transform::default(),
+                        CollisionShape::TriMesh {
+                            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+                            indices: vec![[0, 1, 2]],
+                        },
+                    ),
+                    (Transform::default(), CollisionShape::Compound(Vec::new())),
+                ]),
+            ),
+        ])
+        .collider_builder()
+        .build();
+
+        let compound = collider
+            .shape()
+            .as_compound()
+            .expect("Created shape was not a compound");
+
+        assert_eq!(compound.shapes().len(), 2);
+        assert!(compound.shapes()[1].1.as_ball().is_some());
+        assert_eq!(compound.shapes()[1].0.translation.x, 2.0);
+        assert_eq!(compound.shapes()[1].0.translation.y, 3.0);
+    }
+
+    /// A "L" shape, made of 3 squares
+    #[cfg(dim2)]
+    fn decomposition_mesh() -> (Vec<Vec3>, Vec<[u32; 3]>) {
+        (
+            vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(2.0, 1.0, 0.0),
+                Vec3::new(0.0, 2.0, 0.0),
+                Vec3::new(1.0, 2.0, 0.0),
+            ],
+            vec![
+                [0, 1, 4],
+                [0, 4, 3],
+                [1, 2, 5],
+                [1, 5, 4],
+                [3, 4, 7],
+                [3, 7, 6],
+            ],
+        )
+    }
+
+    /// A cube
+    #[cfg(dim3)]
+    fn decomposition_mesh() -> (Vec<Vec3>, Vec<[u32; 3]>) {
+        (
+            vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 1.0),
+                Vec3::new(1.0, 1.0, 1.0),
+                Vec3::new(0.0, 1.0, 1.0),
+            ],
+            vec![
+                [0, 2, 1],
+                [0, 3, 2],
+                [4, 5, 6],
+                [4, 6, 7],
+                [0, 1, 5],
+                [0, 5, 4],
+                [1, 2, 6],
+                [1, 6, 5],
+                [2, 3, 7],
+                [2, 7, 6],
+                [3, 0, 4],
+                [3, 4, 7],
+            ],
+        )
+    }
+
+    #[test]
+    fn build_convex_decomposition() {
+        let (vertices, indices) = decomposition_mesh();
+
+        let collider = CollisionShape::ConvexDecomposition {
+            vertices,
+            indices,
+            parameters: ConvexDecompositionParameters {
+                resolution: 8,
+                ..Default::default()
+            },
+        }
+        .collider_builder()
+        .build();
+
+        let compound = collider
+            .shape()
+            .as_compound()
+            .expect("Created shape was not a compound");
+
+        assert!(!compound.shapes().is_empty());
+    }
+
+    #[test]
+    fn convex_decompositions_are_reused_while_used() {
+        let (vertices, indices) = decomposition_mesh();
+        let shape = CollisionShape::ConvexDecomposition {
+            vertices,
+            indices,
+            parameters: ConvexDecompositionParameters {
+                resolution: 8,
+                ..Default::default()
+            },
+        };
+        let mut decompositions = ConvexDecompositions::default();
+
+        let first = shape.cached_collider_builder(&mut decompositions).build();
+        let second = shape.cached_collider_builder(&mut decompositions).build();
+        let part = |collider: &Collider| {
+            collider.shape().as_compound().unwrap().shapes()[0]
+                .1
+                 .0
+                .clone()
+        };
+        assert!(std::sync::Arc::ptr_eq(&part(&first), &part(&second)));
+
+        decompositions.remove_unused();
+        assert_eq!(decompositions.0.len(), 1);
+
+        drop((first, second));
+        decompositions.remove_unused();
+        assert!(decompositions.0.is_empty());
+    }
+
+    #[test]
+    #[cfg(dim2)]
+    fn outline_of_triangles() {
+        let mut segments = outline(&[[0, 1, 2], [1, 3, 2]]);
+        segments.sort_unstable();
+
+        assert_eq!(segments, vec![[0, 1], [1, 3], [2, 0], [3, 2]]);
+    }
+
+    #[test]
+    fn build_segment() {
+        let collider = CollisionShape::Segment {
+            a: Vec3::ZERO,
+            b: Vec3::new(1.0, 2.0, 0.0),
+        }
+        .collider_builder()
+        .build();
+
+        let segment = collider
+            .shape()
+            .as_segment()
+            .expect("Created shape was not a segment");
+
+        assert_eq!(segment.a.x, 0.0);
+        assert_eq!(segment.b.x, 1.0);
+        assert_eq!(segment.b.y, 2.0);
+    }
+
+    #[test]
+    fn build_triangle() {
+        let collider = CollisionShape::Triangle {
+            a: Vec3::ZERO,
+            b: Vec3::X,
+            c: Vec3::Y,
+        }
+        .collider_builder()
+        .build();
+
+        let triangle = collider
+            .shape()
+            .as_triangle()
+            .expect("Created shape was not a triangle");
+
+        assert_eq!(triangle.b.x, 1.0);
+        assert_eq!(triangle.c.y, 1.0);
+    }
+
+    #[test]
+    fn build_halfspace() {
+        let collider = CollisionShape::HalfSpace {
+            outward_normal: Vec3::Y * 2.0,
+        }
+        .collider_builder()
+        .build();
+
+        let halfspace = collider
+            .shape()
+            .as_halfspace()
+            .expect("Created shape was not a half-space");
+
+        assert_eq!(halfspace.normal.x, 0.0);
+        assert_eq!(halfspace.normal.y, 1.0);
+    }
+
+    #[test]
+    #[cfg(dim3)]
+    fn build_cylinder() {
+        let collider = CollisionShape::Cylinder {
+            half_height: 2.0,
+            radius: 1.0,
+        }
+        .collider_builder()
+        .build();
+
+        let cylinder = collider
+            .shape()
+            .as_cylinder()
+            .expect("Created shape was not a cylinder");
+
+        assert_eq!(cylinder.half_height, 2.0);
+        assert_eq!(cylinder.radius, 1.0);
+    }
+
+    #[test]
+    #[cfg(dim3)]
+    fn build_cone() {
+        let collider = CollisionShape::Cone {
+            half_height: 2.0,
+            radius: 1.0,
+        }
+        .collider_builder()
+        .build();
+
+        let cone = collider
+            .shape()
+            .as_cone()
+            .expect("Created shape was not a cone");
+
+        assert_eq!(cone.half_height, 2.0);
+        assert_eq!(cone.radius, 1.0);
+    }
 }
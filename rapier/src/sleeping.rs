@@ -0,0 +1,119 @@
+use bevy::app::Events;
+use bevy::prelude::*;
+
+use heron_core::{BodySleep, BodyWake, SleepThreshold, Sleeping};
+
+use crate::rapier::dynamics::{RigidBodyActivation, RigidBodyHandle, RigidBodySet};
+
+pub(crate) fn force_sleep(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut sleep_events: ResMut<'_, Events<BodySleep>>,
+    query: Query<'_, (Entity, &RigidBodyHandle), Added<Sleeping>>,
+) {
+    for (entity, handle) in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            if !body.is_sleeping() {
+                body.sleep();
+                sleep_events.send(BodySleep(entity));
+            }
+        }
+    }
+}
+
+pub(crate) fn force_wake_up(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut wake_events: ResMut<'_, Events<BodyWake>>,
+    handles: Query<'_, &RigidBodyHandle, Without<Sleeping>>,
+    removed: RemovedComponents<'_, Sleeping>,
+) {
+    for entity in removed.iter() {
+        let handle = match handles.get(entity) {
+            Ok(handle) => *handle,
+            // The component has been inserted again since (for instance by a snapshot restoration)
+            Err(_) => continue,
+        };
+
+        if bodies.get(handle).map_or(false, |body| body.is_sleeping()) {
+            if let Some(body) = bodies.get_mut(handle) {
+                body.wake_up(true);
+            }
+            wake_events.send(BodyWake(entity));
+        }
+    }
+}
+
+pub(crate) fn update_sleep_threshold(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    query: Query<'_, (&RigidBodyHandle, &SleepThreshold), Changed<SleepThreshold>>,
+) {
+    for (handle, SleepThreshold(threshold)) in query.iter() {
+        if let Some(body) = bodies.get_mut(*handle) {
+            body.activation_mut().threshold = *threshold;
+        }
+    }
+}
+
+pub(crate) fn reset_sleep_threshold(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Query<'_, &RigidBodyHandle>,
+    removed: RemovedComponents<'_, SleepThreshold>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.activation_mut().threshold = RigidBodyActivation::default_threshold();
+            }
+        });
+}
+
+/// Marker of the bodies created with the [`Sleeping`] component, that haven't been stepped yet
+pub(crate) struct NewSleepingBody;
+
+/// Insert or remove the [`Sleeping`] component, after the physics engine put bodies to sleep or
+/// woke them up
+pub(crate) fn update_sleeping_component(
+    mut commands: Commands<'_>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut sleep_events: ResMut<'_, Events<BodySleep>>,
+    mut wake_events: ResMut<'_, Events<BodyWake>>,
+    query: Query<
+        '_,
+        (
+            Entity,
+            &RigidBodyHandle,
+            Option<&Sleeping>,
+            Option<&NewSleepingBody>,
+        ),
+    >,
+) {
+    for (entity, handle, sleeping, new_body) in query.iter() {
+        if new_body.is_some() {
+            commands.entity(entity).remove::<NewSleepingBody>();
+        }
+
+        // The body is only borrowed mutably if it is modified, because rapier processes the
+        // modified bodies in the order of the modifications
+        let is_sleeping = match bodies.get(*handle) {
+            Some(body) if body.is_dynamic() => body.is_sleeping(),
+            _ => continue,
+        };
+
+        // Rapier wakes up the new bodies during their first step
+        if new_body.is_some() && sleeping.is_some() {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.sleep();
+            }
+            continue;
+        }
+
+        if is_sleeping && sleeping.is_none() {
+            commands.entity(entity).insert(Sleeping);
+            sleep_events.send(BodySleep(entity));
+        } else if !is_sleeping && sleeping.is_some() {
+            commands.entity(entity).remove::<Sleeping>();
+            wake_events.send(BodyWake(entity));
+        }
+    }
+}
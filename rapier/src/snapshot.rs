@@ -0,0 +1,280 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::{EntityMap, MapEntities, MapEntitiesError};
+use bevy::ecs::prelude::*;
+use bevy::transform::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use heron_core::{Sleeping, Velocity};
+
+use crate::body::{self, set_bevy_transform};
+use crate::convert::IntoBevy;
+use crate::joint;
+use crate::rapier::dynamics::{
+    IslandManager, JointHandle, JointSet, RigidBodyHandle, RigidBodySet,
+};
+use crate::rapier::geometry::{BroadPhase, ColliderHandle, ColliderSet, NarrowPhase};
+use crate::rapier::pipeline::QueryPipeline;
+use crate::shape;
+use crate::velocity::set_velocity_component;
+
+/// Complete state of the physics world, that can be restored later
+///
+/// It contains the rigid bodies, collision shapes and joints, as well as the contacts and the
+/// islands of sleeping bodies, and the entities they belong to.
+///
+/// It is only available with the feature `serde-2d` or `serde-3d`, and can be serialized with serde.
+///
+/// When the snapshot is restored in another app (or after the entities have been re-spawned), the
+/// entities must be remapped with [`MapEntities::map_entities`]. For instance, with the
+/// [`EntityMap`] returned when writing a bevy scene into the world.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_rapier::PhysicsSnapshot;
+/// fn save(world: &mut World) {
+///     let snapshot = PhysicsSnapshot::take(world);
+///     // ... Store the snapshot
+///     # let _ = snapshot;
+/// }
+///
+/// fn load(world: &mut World, snapshot: PhysicsSnapshot) {
+///     snapshot.restore(world);
+/// }
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct PhysicsSnapshot {
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    joints: JointSet,
+    islands: IslandManager,
+    broad_phase: BroadPhase,
+    narrow_phase: NarrowPhase,
+    body_handles: Vec<(u64, RigidBodyHandle)>,
+    collider_handles: Vec<(u64, ColliderHandle)>,
+    joint_handles: Vec<(u64, JointHandle)>,
+}
+
+impl PhysicsSnapshot {
+    /// Take a snapshot of the physics world
+    ///
+    /// # Panics
+    ///
+    /// Panics if the physics plugin isn't installed in the world
+    #[must_use]
+    pub fn take(world: &World) -> Self {
+        Self {
+            bodies: resource::<RigidBodySet>(world).clone(),
+            colliders: resource::<ColliderSet>(world).clone(),
+            joints: resource::<JointSet>(world).clone(),
+            islands: clone_islands(resource::<IslandManager>(world)),
+            broad_phase: resource::<BroadPhase>(world).clone(),
+            narrow_phase: resource::<NarrowPhase>(world).clone(),
+            body_handles: handles(resource::<body::HandleMap>(world).iter()),
+            collider_handles: handles(resource::<shape::HandleMap>(world).iter()),
+            joint_handles: handles(resource::<joint::HandleMap>(world).iter()),
+        }
+    }
+
+    /// Restore the physics world to the state of the snapshot
+    ///
+    /// The rigid bodies, collision shapes and joints of the snapshot are attached back to their
+    /// entities, and the transforms and velocities of the bodies are updated. The ones whose entity doesn't exist
+    /// are discarded. The entities that are not part of the snapshot are added to the physics world
+    /// again, at their current position, during the next physics update.
+    ///
+    /// The [`Sleeping`] components are inserted or removed to match the
+    /// restored bodies, without sending any sleep or wake events. The updated components are not
+    /// applied back to the physics world, so that the next step starts exactly from the state of
+    /// the snapshot.
+    pub fn restore(self, world: &mut World) {
+        let Self {
+            mut bodies,
+            mut colliders,
+            mut joints,
+            mut islands,
+            broad_phase,
+            narrow_phase,
+            body_handles,
+            collider_handles,
+            joint_handles,
+        } = self;
+
+        detach_handles::<RigidBodyHandle>(world);
+        detach_handles::<ColliderHandle>(world);
+        detach_handles::<JointHandle>(world);
+
+        let mut body_map = body::HandleMap::default();
+        for (entity, handle) in entities(body_handles) {
+            if world.get_entity(entity).is_some() {
+                world.entity_mut(entity).insert(handle);
+                body_map.insert(entity, handle);
+            } else {
+                bodies.remove(handle, &mut islands, &mut colliders, &mut joints);
+            }
+        }
+
+        let mut collider_map = shape::HandleMap::default();
+        for (entity, handle) in entities(collider_handles) {
+            if world.get_entity(entity).is_some() && colliders.get(handle).is_some() {
+                world.entity_mut(entity).insert(handle);
+                collider_map.insert(entity, handle);
+            } else {
+                colliders.remove(handle, &mut islands, &mut bodies, true);
+            }
+        }
+
+        let mut joint_map = joint::HandleMap::default();
+        for (entity, handle) in entities(joint_handles) {
+            if world.get_entity(entity).is_some() && joints.get(handle).is_some() {
+                world.entity_mut(entity).insert(handle);
+                joint_map.insert(entity, handle);
+            } else {
+                joints.remove(handle, &mut islands, &mut bodies, true);
+            }
+        }
+
+        update_bevy_transforms(world, &bodies, &body_map);
+        update_velocities(world, &bodies, &body_map);
+        update_sleeping_components(world, &bodies, &body_map);
+
+        let mut query_pipeline = QueryPipeline::new();
+        query_pipeline.update(&islands, &bodies, &colliders);
+
+        world.insert_resource(bodies);
+        world.insert_resource(colliders);
+        world.insert_resource(joints);
+        world.insert_resource(islands);
+        world.insert_resource(broad_phase);
+        world.insert_resource(narrow_phase);
+        world.insert_resource(query_pipeline);
+        world.insert_resource(body_map);
+        world.insert_resource(collider_map);
+        world.insert_resource(joint_map);
+    }
+}
+
+impl Clone for PhysicsSnapshot {
+    fn clone(&self) -> Self {
+        Self {
+            bodies: self.bodies.clone(),
+            colliders: self.colliders.clone(),
+            joints: self.joints.clone(),
+            islands: clone_islands(&self.islands),
+            broad_phase: self.broad_phase.clone(),
+            narrow_phase: self.narrow_phase.clone(),
+            body_handles: self.body_handles.clone(),
+            collider_handles: self.collider_handles.clone(),
+            joint_handles: self.joint_handles.clone(),
+        }
+    }
+}
+
+impl MapEntities for PhysicsSnapshot {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        for (bits, handle) in &mut self.body_handles {
+            let entity = entity_map.get(Entity::from_bits(*bits))?;
+            *bits = entity.to_bits();
+            if let Some(body) = self.bodies.get_mut(*handle) {
+                body.user_data = entity.to_bits().into();
+            }
+        }
+
+        for (bits, handle) in &mut self.collider_handles {
+            let entity = entity_map.get(Entity::from_bits(*bits))?;
+            *bits = entity.to_bits();
+            if let Some(collider) = self.colliders.get_mut(*handle) {
+                collider.user_data = entity.to_bits().into();
+            }
+        }
+
+        for (bits, _) in &mut self.joint_handles {
+            *bits = entity_map.get(Entity::from_bits(*bits))?.to_bits();
+        }
+
+        Ok(())
+    }
+}
+
+fn resource<T: Component>(world: &World) -> &T {
+    world
+        .get_resource::<T>()
+        .expect("The physics plugin is not installed")
+}
+
+/// The island manager of rapier isn't `Clone`, but it is serializable
+///
+/// Its serialized form contains everything but the temporary buffers of the island computation.
+fn clone_islands(islands: &IslandManager) -> IslandManager {
+    let bytes = bincode::serialize(islands).expect("Failed to serialize the island manager");
+    bincode::deserialize(&bytes).expect("Failed to deserialize the island manager")
+}
+
+/// Handles of the entities, sorted by entity so that the snapshot doesn't depend on the order of
+/// the hash map
+fn handles<'a, H: Copy + 'a>(map: impl Iterator<Item = (&'a Entity, &'a H)>) -> Vec<(u64, H)> {
+    let mut handles: Vec<(u64, H)> = map
+        .map(|(entity, handle)| (entity.to_bits(), *handle))
+        .collect();
+    handles.sort_unstable_by_key(|(bits, _)| *bits);
+    handles
+}
+
+fn entities<H>(handles: Vec<(u64, H)>) -> impl Iterator<Item = (Entity, H)> {
+    handles
+        .into_iter()
+        .map(|(bits, handle)| (Entity::from_bits(bits), handle))
+}
+
+/// Remove the handles of the current physics world from the entities
+fn detach_handles<H: Component>(world: &mut World) {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, With<H>>()
+        .iter(world)
+        .collect();
+
+    for entity in entities {
+        world.entity_mut(entity).remove::<H>();
+    }
+}
+
+fn update_bevy_transforms(world: &mut World, bodies: &RigidBodySet, handles: &body::HandleMap) {
+    let mut query = world.query::<(Option<&mut Transform>, &mut GlobalTransform)>();
+    for (entity, handle) in handles {
+        if let (Some(body), Ok((local, global))) =
+            (bodies.get(*handle), query.get_mut(world, *entity))
+        {
+            let (translation, rotation) = body.position().into_bevy();
+            set_bevy_transform(local, global, translation, rotation);
+        }
+    }
+}
+
+fn update_velocities(world: &mut World, bodies: &RigidBodySet, handles: &body::HandleMap) {
+    let mut query = world.query::<&mut Velocity>();
+    for (entity, handle) in handles {
+        if let (Some(body), Ok(mut velocity)) = (
+            bodies.get(*handle).filter(|it| it.is_dynamic()),
+            query.get_mut(world, *entity),
+        ) {
+            set_velocity_component(body, &mut velocity);
+        }
+    }
+}
+
+fn update_sleeping_components(world: &mut World, bodies: &RigidBodySet, handles: &body::HandleMap) {
+    for (entity, handle) in handles {
+        let is_sleeping = match bodies.get(*handle).filter(|it| it.is_dynamic()) {
+            Some(body) => body.is_sleeping(),
+            None => continue,
+        };
+
+        let mut entity = world.entity_mut(*entity);
+        if is_sleeping && !entity.contains::<Sleeping>() {
+            entity.insert(Sleeping);
+        } else if !is_sleeping && entity.contains::<Sleeping>() {
+            entity.remove::<Sleeping>();
+        }
+    }
+}
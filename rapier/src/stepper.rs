@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use bevy::ecs::prelude::*;
+use bevy::ecs::schedule::Stage;
+
+use heron_core::{PhysicsSteps, PhysicsTime};
+
+use crate::pipeline::substep_duration;
+use crate::rapier::dynamics::IntegrationParameters;
+
+/// Performs physics steps on demand, independently of the frames and of the [`Time`](bevy::core::Time)
+///
+/// Each call to [`PhysicsStepper::step`] runs a complete physics step: the components are applied
+/// to the physics world, the simulation is advanced by the duration of
+/// [`PhysicsSteps`] (or its maximum, for [`PhysicsSteps::from_max_delta_time`]), and the
+/// components and events are updated. It makes possible to run several steps back to back in the
+/// same frame, changing the components (the inputs) between each step.
+///
+/// Together with the `PhysicsSnapshot` (available with the `serde-2d` and `serde-3d` features), it
+/// allows rollback and resimulation: the snapshot restores the complete physics world (including
+/// the contacts), so that stepping again with the same inputs gives the same results.
+///
+/// To prevent the physics steps from being performed automatically at every frame, use
+/// [`PhysicsSteps::manual`]. Note that in this mode, the systems added to the physics stages (like
+/// the deprecated `stage::UPDATE`) never run. The systems that change the components should run
+/// before calling [`PhysicsStepper::step`] instead.
+///
+/// The components are applied to the physics world by the same systems as for the automatic
+/// steps, so that a stepper can be created at any time, and only the components that changed since
+/// the last step (automatic or not) are applied.
+///
+/// Note that the simulation is only deterministic across platforms if the `enhanced-determinism`
+/// feature of rapier is enabled.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # use heron_rapier::PhysicsStepper;
+/// fn simulate(world: &mut World, stepper: &mut PhysicsStepper, player: Entity, inputs: &[Vec3]) {
+///     for input in inputs {
+///         world.get_mut::<Velocity>(player).unwrap().linear = *input;
+///         stepper.step(world);
+///     }
+/// }
+/// ```
+pub struct PhysicsStepper {
+    schedule: Schedule,
+}
+
+impl Default for PhysicsStepper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicsStepper {
+    /// Create a new stepper
+    #[must_use]
+    pub fn new() -> Self {
+        let mut schedule = Schedule::default();
+        schedule.add_stage(
+            "heron-step",
+            // Single-threaded for the steps to be deterministic
+            SystemStage::single_threaded().with_system_set(crate::step_systems()),
+        );
+        Self { schedule }
+    }
+
+    /// Perform one physics step
+    ///
+    /// # Panics
+    ///
+    /// Panics if the physics plugin isn't installed in the world
+    pub fn step(&mut self, world: &mut World) {
+        let dt = substep_duration(
+            world
+                .get_resource::<PhysicsSteps>()
+                .expect("The physics plugin is not installed"),
+            world
+                .get_resource::<PhysicsTime>()
+                .expect("The physics plugin is not installed"),
+            Duration::MAX,
+        );
+
+        world
+            .get_resource_mut::<IntegrationParameters>()
+            .expect("The physics plugin is not installed")
+            .dt = dt;
+
+        update_physics_world(world);
+        self.schedule.run(world);
+    }
+}
+
+/// Stages that update the physics world from the components, before the physics step
+///
+/// They are shared by the app and the [`PhysicsStepper`], because the systems only apply the
+/// components that changed since they last ran.
+pub(crate) struct PhysicsStages(Schedule);
+
+impl PhysicsStages {
+    pub(crate) fn new() -> Self {
+        let mut schedule = Schedule::default();
+        crate::add_physics_stages(&mut schedule);
+        Self(schedule)
+    }
+}
+
+/// Run the [`PhysicsStages`]
+pub(crate) fn update_physics_world(world: &mut World) {
+    world.resource_scope(|world, mut stages: Mut<'_, PhysicsStages>| stages.0.run(world));
+}
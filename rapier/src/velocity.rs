@@ -1,8 +1,7 @@
 use bevy::ecs::prelude::*;
-use bevy::math::prelude::*;
 
 use heron_core::utils::NearZero;
-use heron_core::{PhysicsSteps, RigidBody, Velocity};
+use heron_core::{RigidBody, Velocity};
 
 use crate::convert::{IntoBevy, IntoRapier};
 use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
@@ -17,6 +16,13 @@ pub(crate) fn update_rapier_velocity(
 
     for (handle, _, velocity) in dynamic_bodies {
         if let Some(body) = bodies.get_mut(*handle) {
+            // The component may only have been updated from the physics world
+            let mut current = *velocity;
+            set_velocity_component(body, &mut current);
+            if current == *velocity {
+                continue;
+            }
+
             let wake_up = !velocity.is_near_zero();
             body.set_linvel(velocity.linear.into_rapier(), wake_up);
             body.set_angvel(velocity.angular.into_rapier(), wake_up);
@@ -24,26 +30,47 @@ pub(crate) fn update_rapier_velocity(
     }
 }
 
-pub(crate) fn apply_velocity_to_kinematic_bodies(
-    mut bodies: ResMut<'_, RigidBodySet>,
-    physics_step: Res<'_, PhysicsSteps>,
-    bevy_time: Res<'_, bevy::core::Time>,
-    query: Query<'_, (&RigidBodyHandle, &RigidBody, &Velocity)>,
-) {
-    let delta_time = physics_step
-        .duration()
-        .exact(bevy_time.delta())
-        .as_secs_f32();
-    let kinematic_bodies = query
-        .iter()
-        .filter(|(_, body_type, _)| matches!(body_type, RigidBody::KinematicVelocityBased));
+pub(crate) use kinematic::KinematicVelocities;
 
-    for (handle, _, velocity) in kinematic_bodies {
-        if let Some(body) = bodies.get_mut(*handle) {
-            let (mut translation, mut rotation) = body.position().into_bevy();
-            translation += velocity.linear * delta_time;
-            rotation *= Quat::from(velocity.angular * delta_time);
-            body.set_next_kinematic_position((translation, rotation).into_rapier())
+// We have to make a module here so that we can allow missing docs on the structs generated by the
+// derive macro
+#[allow(missing_docs)]
+mod kinematic {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+    use bevy::ecs::system::SystemParam;
+
+    /// Velocities of the [`RigidBody::KinematicVelocityBased`] bodies
+    ///
+    /// Rapier resets the velocity of the kinematic bodies after every step, so it is applied again
+    /// before every step (including the substeps). As rapier integrates it over the duration
+    /// simulated by the step, the movement is scaled by the [`PhysicsTime`](heron_core::PhysicsTime),
+    /// like the rest of the simulation.
+    #[derive(SystemParam)]
+    pub struct KinematicVelocities<'a> {
+        query: Query<
+            'a,
+            (
+                &'static RigidBodyHandle,
+                &'static RigidBody,
+                &'static Velocity,
+            ),
+        >,
+    }
+
+    impl KinematicVelocities<'_> {
+        pub(crate) fn apply(&self, bodies: &mut RigidBodySet) {
+            let kinematic_bodies = self
+                .query
+                .iter()
+                .filter(|(_, body_type, _)| matches!(body_type, RigidBody::KinematicVelocityBased));
+
+            for (handle, _, velocity) in kinematic_bodies {
+                if let Some(body) = bodies.get_mut(*handle) {
+                    body.set_linvel(velocity.linear.into_rapier(), false);
+                    body.set_angvel(velocity.angular.into_rapier(), false);
+                }
+            }
         }
     }
 }
@@ -54,17 +81,25 @@ pub(crate) fn update_velocity_component(
 ) {
     for (handle, mut velocity) in velocities.iter_mut() {
         if let Some(body) = bodies.get(*handle).filter(|it| it.is_dynamic()) {
-            velocity.linear = (*body.linvel()).into_bevy();
+            set_velocity_component(body, &mut velocity);
+        }
+    }
+}
 
-            #[cfg(dim2)]
-            {
-                velocity.angular = heron_core::AxisAngle::from(bevy::math::Vec3::Z * body.angvel());
-            }
+/// Copy the velocity of the rapier body into the [`Velocity`] component
+pub(crate) fn set_velocity_component(
+    body: &crate::rapier::dynamics::RigidBody,
+    velocity: &mut Velocity,
+) {
+    velocity.linear = (*body.linvel()).into_bevy();
 
-            #[cfg(dim3)]
-            {
-                velocity.angular = (*body.angvel()).into_bevy().into();
-            }
-        }
+    #[cfg(dim2)]
+    {
+        velocity.angular = heron_core::AxisAngle::from(bevy::math::Vec3::Z * body.angvel());
+    }
+
+    #[cfg(dim3)]
+    {
+        velocity.angular = (*body.angvel()).into_bevy().into();
     }
 }
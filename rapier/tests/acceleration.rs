@@ -55,8 +55,8 @@ fn body_is_created_with_acceleration() {
         let body = bodies.get(*app.world.get(entity).unwrap()).unwrap();
 
         println!("{:?}", body);
-        assert_eq!(body.linvel().into_bevy(), Vec3::ZERO);
-        assert_eq_angular(body.angvel(), AxisAngle::from(Vec3::ZERO));
+        assert_eq!(body.linvel().into_bevy(), linear);
+        assert_eq_angular(body.angvel(), angular);
     }
 
     app.update();
@@ -66,8 +66,8 @@ fn body_is_created_with_acceleration() {
     let body = bodies.get(*app.world.get(entity).unwrap()).unwrap();
 
     println!("{:?}", body);
-    assert_eq!(body.linvel().into_bevy(), linear);
-    assert_eq_angular(body.angvel(), angular);
+    assert_eq!(body.linvel().into_bevy(), linear * 2.0);
+    assert_eq_angular(body.angvel(), AxisAngle::new(Vec3::Z, 2.0));
 }
 
 #[test]
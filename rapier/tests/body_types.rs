@@ -107,7 +107,7 @@ fn create_kinematic_velocity_based_body() {
     let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
     let body = bodies.get(*app.world.get(entity).unwrap()).unwrap();
 
-    assert!(body.is_kinematic())
+    assert_eq!(body.body_type(), RigidBodyType::KinematicVelocityBased)
 }
 
 #[test]
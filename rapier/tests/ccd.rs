@@ -0,0 +1,96 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, ContinuousCollisionDetection, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn is_ccd_enabled(app: &App, entity: Entity) -> bool {
+    app.world
+        .get_resource::<RigidBodySet>()
+        .unwrap()
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap()
+        .is_ccd_enabled()
+}
+
+#[test]
+fn ccd_is_disabled_by_default() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    assert!(!is_ccd_enabled(&app, entity));
+}
+
+#[test]
+fn body_is_created_with_ccd() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+    app.world
+        .entity_mut(entity)
+        .insert(ContinuousCollisionDetection);
+
+    app.update();
+
+    assert!(is_ccd_enabled(&app, entity));
+}
+
+#[test]
+fn ccd_can_be_toggled_without_recreating_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+
+    app.world
+        .entity_mut(entity)
+        .insert(ContinuousCollisionDetection);
+
+    app.update();
+
+    assert!(is_ccd_enabled(&app, entity));
+
+    app.world
+        .entity_mut(entity)
+        .remove::<ContinuousCollisionDetection>();
+
+    app.update();
+
+    assert!(!is_ccd_enabled(&app, entity));
+    assert_eq!(*app.world.get::<RigidBodyHandle>(entity).unwrap(), handle);
+}
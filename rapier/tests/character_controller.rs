@@ -0,0 +1,312 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{CharacterController, CharacterControllerOutput, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_obstacle(app: &mut App, translation: Vec3, half_extends: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::from_translation(translation),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends,
+                border_radius: None,
+            },
+        ))
+        .id()
+}
+
+/// Spawn a floor whose top surface is at `y = 0.5`
+fn spawn_floor(app: &mut App) -> Entity {
+    spawn_obstacle(app, Vec3::ZERO, Vec3::new(10.0, 0.5, 10.0))
+}
+
+fn spawn_character(app: &mut App, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 0.5 },
+            CharacterController::default(),
+        ))
+        .id()
+}
+
+fn move_character(app: &mut App, character: Entity, translation: Vec3) {
+    app.world
+        .get_mut::<CharacterController>(character)
+        .unwrap()
+        .translation = Some(translation);
+    app.update();
+}
+
+#[test]
+fn character_stops_on_the_ground() {
+    let mut app = test_app();
+    let floor = spawn_floor(&mut app);
+    let character = spawn_character(&mut app, Vec3::Y * 2.0);
+    app.update();
+
+    move_character(&mut app, character, Vec3::Y * -5.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!((translation.y - 1.0).abs() < 0.05);
+
+    let output = app
+        .world
+        .get::<CharacterControllerOutput>(character)
+        .unwrap();
+    assert!(output.grounded);
+    assert_eq!(output.ground_entity, Some(floor));
+    assert!(output.ground_normal.unwrap().dot(Vec3::Y) > 0.99);
+}
+
+#[test]
+fn character_is_blocked_by_walls() {
+    let mut app = test_app();
+    spawn_floor(&mut app);
+    let wall = spawn_obstacle(&mut app, Vec3::X * 3.0, Vec3::new(0.5, 10.0, 10.0));
+    let character = spawn_character(&mut app, Vec3::Y * 1.02);
+    app.update();
+
+    move_character(&mut app, character, Vec3::X * 5.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x < 2.0);
+    assert!(translation.x > 1.9);
+
+    let output = app
+        .world
+        .get::<CharacterControllerOutput>(character)
+        .unwrap();
+    let hit = output.hits.iter().find(|hit| hit.entity == wall).unwrap();
+    assert!(hit.normal.dot(-Vec3::X) > 0.99);
+    assert!((hit.point.unwrap().x - 2.5).abs() < 0.05);
+}
+
+#[test]
+fn character_steps_onto_low_obstacles() {
+    let mut app = test_app();
+    spawn_floor(&mut app);
+    spawn_obstacle(
+        &mut app,
+        Vec3::new(2.5, 0.6, 0.0),
+        Vec3::new(1.0, 0.1, 10.0),
+    );
+    let character = spawn_character(&mut app, Vec3::Y * 1.02);
+    app.update();
+
+    move_character(&mut app, character, Vec3::X * 2.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x > 1.9);
+    assert!(translation.y > 1.15);
+    assert!(
+        app.world
+            .get::<CharacterControllerOutput>(character)
+            .unwrap()
+            .grounded
+    );
+}
+
+#[test]
+fn character_cannot_step_onto_high_obstacles() {
+    let mut app = test_app();
+    spawn_floor(&mut app);
+    spawn_obstacle(
+        &mut app,
+        Vec3::new(2.5, 1.0, 0.0),
+        Vec3::new(1.0, 0.5, 10.0),
+    );
+    let character = spawn_character(&mut app, Vec3::Y * 1.02);
+    app.update();
+
+    move_character(&mut app, character, Vec3::X * 2.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x < 1.5);
+    assert!(translation.y < 1.1);
+}
+
+#[test]
+fn character_rides_moving_platforms() {
+    let mut app = test_app();
+    let platform = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 0.5, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id();
+    let character = spawn_character(&mut app, Vec3::Y * 1.005);
+    app.update();
+
+    // Land on the platform
+    move_character(&mut app, character, Vec3::Y * -0.1);
+    assert!(
+        app.world
+            .get::<CharacterControllerOutput>(character)
+            .unwrap()
+            .grounded
+    );
+
+    app.world
+        .get_mut::<Transform>(platform)
+        .unwrap()
+        .translation
+        .x = 1.0;
+    app.update();
+    app.update();
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!((translation.x - 1.0).abs() < 0.05);
+}
+
+#[test]
+fn character_forgets_its_ground_when_the_controller_is_removed() {
+    let mut app = test_app();
+    let platform = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 0.5, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id();
+    let character = spawn_character(&mut app, Vec3::Y * 1.005);
+    app.update();
+    move_character(&mut app, character, Vec3::Y * -0.1);
+
+    app.world
+        .entity_mut(character)
+        .remove::<CharacterController>();
+    app.update();
+
+    app.world
+        .get_mut::<Transform>(platform)
+        .unwrap()
+        .translation
+        .x = 1.0;
+    app.update();
+    app.update();
+
+    // The movement of the platform while the character had no controller is not applied
+    app.world
+        .entity_mut(character)
+        .insert(CharacterController::default());
+    app.update();
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x.abs() < 0.05);
+}
+
+#[test]
+fn character_translation_is_relative_to_the_parent() {
+    let mut app = test_app();
+    let rotation = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+    let parent = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_rotation(rotation),
+            GlobalTransform::from_rotation(rotation),
+        ))
+        .id();
+    let character = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::from_rotation(rotation),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 0.5 },
+            CharacterController::default(),
+        ))
+        .id();
+    app.world.entity_mut(parent).push_children(&[character]);
+    app.update();
+
+    move_character(&mut app, character, Vec3::X);
+
+    // The parent is rotated by 90 degrees, so the world-space `x` axis is its local `-y` axis
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!((translation - Vec3::new(0.0, -1.0, 0.0)).length() < 0.01);
+}
+
+#[test]
+fn character_cannot_move_further_into_penetrated_obstacles() {
+    let mut app = test_app();
+    spawn_floor(&mut app);
+    spawn_obstacle(&mut app, Vec3::X * 3.0, Vec3::new(0.5, 10.0, 10.0));
+
+    // The surface of the wall is at `x = 2.5`, so the character penetrates it
+    let character = spawn_character(&mut app, Vec3::new(2.2, 1.02, 0.0));
+    app.update();
+
+    move_character(&mut app, character, Vec3::X * 5.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x < 2.0);
+    assert!(translation.x > 1.9);
+}
+
+#[test]
+fn character_ignores_its_own_collision_shapes() {
+    let mut app = test_app();
+    spawn_floor(&mut app);
+    let character = spawn_character(&mut app, Vec3::Y * 1.02);
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::X),
+            GlobalTransform::from_translation(Vec3::new(1.0, 1.02, 0.0)),
+            CollisionShape::Sphere { radius: 0.5 },
+        ))
+        .id();
+    app.world.entity_mut(character).push_children(&[child]);
+    app.update();
+
+    move_character(&mut app, character, Vec3::X * 2.0);
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!((translation.x - 2.0).abs() < 0.01);
+    assert!(app
+        .world
+        .get::<CharacterControllerOutput>(character)
+        .unwrap()
+        .hits
+        .is_empty());
+}
@@ -5,7 +5,7 @@ use bevy::core::CorePlugin;
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 
-use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_core::{CollisionShape, PhysicMaterial, PhysicsSteps, RigidBody};
 use heron_rapier::convert::IntoBevy;
 use heron_rapier::RapierPlugin;
 
@@ -146,3 +146,114 @@ fn updating_local_transform_of_a_rigid_body_doesnt_affect_the_shape() {
 
     assert_eq!(actual_translation, Vec3::default());
 }
+
+fn material(friction: f32) -> PhysicMaterial {
+    PhysicMaterial {
+        friction,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn material_of_child_collision_shape_overrides_the_one_of_the_body() {
+    let mut app = test_app();
+
+    let mut shape_with_material = None;
+    let mut shape_without_material = None;
+
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            material(0.5),
+        ))
+        .with_children(|children| {
+            shape_with_material = Some(
+                children
+                    .spawn_bundle((
+                        Transform::default(),
+                        CollisionShape::Sphere { radius: 1.0 },
+                        material(0.9),
+                    ))
+                    .id(),
+            );
+            shape_without_material = Some(
+                children
+                    .spawn_bundle((Transform::default(), CollisionShape::Sphere { radius: 1.0 }))
+                    .id(),
+            );
+        });
+
+    app.update();
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let friction = |entity: Option<Entity>| {
+        colliders
+            .get(*app.world.get::<ColliderHandle>(entity.unwrap()).unwrap())
+            .unwrap()
+            .friction()
+    };
+
+    assert_eq!(friction(shape_with_material), 0.9);
+    assert_eq!(friction(shape_without_material), 0.5);
+}
+
+#[test]
+fn material_of_child_collision_shape_can_be_updated_without_recreating_the_body() {
+    let mut app = test_app();
+
+    let shape = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            material(0.9),
+        ))
+        .id();
+
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            material(0.5),
+        ))
+        .push_children(&[shape])
+        .id();
+
+    app.update();
+
+    let body_handle = *app.world.get::<RigidBodyHandle>(body).unwrap();
+
+    *app.world.get_mut::<PhysicMaterial>(shape).unwrap() = material(0.1);
+
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<RigidBodyHandle>(body).unwrap(),
+        body_handle
+    );
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders
+        .get(*app.world.get::<ColliderHandle>(shape).unwrap())
+        .unwrap();
+
+    assert_eq!(colliders.len(), 1);
+    assert_eq!(collider.friction(), 0.1);
+    assert_eq!(collider.parent(), Some(body_handle));
+
+    app.world.entity_mut(shape).remove::<PhysicMaterial>();
+
+    app.update();
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders
+        .get(*app.world.get::<ColliderHandle>(shape).unwrap())
+        .unwrap();
+
+    assert_eq!(collider.friction(), 0.5);
+}
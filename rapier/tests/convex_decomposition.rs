@@ -0,0 +1,147 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, ConvexDecompositionParameters, Gravity, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -9.81))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+/// Height of the mesh at `x = 0.5`
+#[cfg(dim2)]
+const TOP: f32 = 2.0;
+#[cfg(dim3)]
+const TOP: f32 = 1.0;
+
+/// A "L" shape whose top surfaces are at `y = 2.0` (for `x < 1.0`) and `y = 1.0`
+#[cfg(dim2)]
+fn mesh() -> (Vec<Vec3>, Vec<[u32; 3]>) {
+    (
+        vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+        ],
+        vec![
+            [0, 1, 4],
+            [0, 4, 3],
+            [1, 2, 5],
+            [1, 5, 4],
+            [3, 4, 7],
+            [3, 7, 6],
+        ],
+    )
+}
+
+/// A cube whose top surface is at `y = 1.0`
+#[cfg(dim3)]
+fn mesh() -> (Vec<Vec3>, Vec<[u32; 3]>) {
+    (
+        vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 2.0),
+            Vec3::new(2.0, 0.0, 2.0),
+            Vec3::new(2.0, 1.0, 2.0),
+            Vec3::new(0.0, 1.0, 2.0),
+        ],
+        vec![
+            [0, 2, 1],
+            [0, 3, 2],
+            [4, 5, 6],
+            [4, 6, 7],
+            [0, 1, 5],
+            [0, 5, 4],
+            [1, 2, 6],
+            [1, 6, 5],
+            [2, 3, 7],
+            [2, 7, 6],
+            [3, 0, 4],
+            [3, 4, 7],
+        ],
+    )
+}
+
+#[test]
+fn convex_decomposition_collider_is_created() {
+    let mut app = test_app();
+    let (vertices, indices) = mesh();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::ConvexDecomposition {
+                vertices,
+                indices,
+                parameters: ConvexDecompositionParameters {
+                    resolution: 8,
+                    ..Default::default()
+                },
+            },
+        ))
+        .id();
+
+    let cube = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(0.5, 3.0, 1.0),
+            GlobalTransform::from_xyz(0.5, 3.0, 1.0),
+            RigidBody::Dynamic,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::splat(0.25),
+                border_radius: None,
+            },
+        ))
+        .id();
+
+    for _ in 0..120 {
+        app.update();
+    }
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders
+        .get(*app.world.get::<ColliderHandle>(entity).unwrap())
+        .expect("No collider referenced by the handle");
+    let compound = collider
+        .shape()
+        .as_compound()
+        .expect("Created shape was not a compound");
+    assert!(!compound.shapes().is_empty());
+
+    // The box rests on the top surface at `x = 0.5`
+    let translation = app.world.get::<Transform>(cube).unwrap().translation;
+    assert!(
+        (translation.y - (TOP + 0.25)).abs() < 0.1,
+        "{:?}",
+        translation
+    );
+}
@@ -0,0 +1,96 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Damping, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App, damping: Damping) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            damping,
+        ))
+        .id()
+}
+
+#[test]
+fn body_is_created_with_damping() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Damping::from_linear(1.0).with_angular(2.0));
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert_eq!(body.linear_damping(), 1.0);
+    assert_eq!(body.angular_damping(), 2.0);
+}
+
+#[test]
+fn damping_is_updated_without_recreating_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Damping::from_linear(1.0));
+
+    app.update();
+
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+
+    app.world.get_mut::<Damping>(entity).unwrap().angular = 3.0;
+
+    app.update();
+
+    assert_eq!(*app.world.get::<RigidBodyHandle>(entity).unwrap(), handle);
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies.get(handle).unwrap();
+
+    assert_eq!(body.linear_damping(), 1.0);
+    assert_eq!(body.angular_damping(), 3.0);
+}
+
+#[test]
+fn damping_is_reset_when_removed() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Damping::from_linear(1.0).with_angular(2.0));
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<Damping>();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert_eq!(body.linear_damping(), 0.0);
+    assert_eq!(body.angular_damping(), 0.0);
+}
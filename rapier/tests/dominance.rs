@@ -0,0 +1,78 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Dominance, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App, dominance: Dominance) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            dominance,
+        ))
+        .id()
+}
+
+fn dominance_group(app: &App, entity: Entity) -> i8 {
+    app.world
+        .get_resource::<RigidBodySet>()
+        .unwrap()
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap()
+        .dominance_group()
+}
+
+#[test]
+fn body_is_created_with_dominance() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Dominance(5));
+
+    app.update();
+
+    assert_eq!(dominance_group(&app, entity), 5);
+}
+
+#[test]
+fn dominance_is_updated_without_recreating_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Dominance(5));
+
+    app.update();
+
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+    app.world.get_mut::<Dominance>(entity).unwrap().0 = -2;
+
+    app.update();
+
+    assert_eq!(*app.world.get::<RigidBodyHandle>(entity).unwrap(), handle);
+    assert_eq!(dominance_group(&app, entity), -2);
+
+    app.world.entity_mut(entity).remove::<Dominance>();
+
+    app.update();
+
+    assert_eq!(dominance_group(&app, entity), 0);
+}
@@ -4,7 +4,9 @@ use bevy::core::CorePlugin;
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 
-use heron_core::{CollisionEvent, CollisionShape, PhysicsSteps, RigidBody, Velocity};
+use heron_core::{
+    CollisionEvent, CollisionShape, ContactDetails, PhysicsSteps, RigidBody, Velocity,
+};
 use heron_rapier::RapierPlugin;
 use std::time::Duration;
 
@@ -77,6 +79,104 @@ fn collision_events_are_fired() {
     assert_eq!(events[1].collision_shape_entities(), (entity1, entity2));
 }
 
+#[test]
+fn contact_details_are_fired_for_started_contacts() {
+    let mut app = test_app();
+
+    let entity1 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 10.0 },
+            RigidBody::Static,
+        ))
+        .id();
+
+    let entity2 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::X * 15.0),
+            GlobalTransform::from_translation(Vec3::X * 15.0),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+        ))
+        .id();
+
+    let mut event_reader = app
+        .world
+        .get_resource::<Events<ContactDetails>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    let events = app.world.get_resource::<Events<ContactDetails>>().unwrap();
+    let details: Vec<ContactDetails> = event_reader.iter(&events).cloned().collect();
+
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].collision_shape_entities(), (entity1, entity2));
+    assert!(!details[0].points().is_empty());
+    assert!(details[0].normal().x > 0.9);
+    assert!(details[0].max_penetration_depth() > 0.0);
+}
+
+#[test]
+fn contact_points_have_the_normal_of_their_manifold() {
+    let mut app = test_app();
+
+    // A sphere between the two parts of a compound, touching both
+    let compound = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Compound(vec![
+                (
+                    Transform::from_translation(Vec3::X * -5.0),
+                    CollisionShape::Sphere { radius: 4.0 },
+                ),
+                (
+                    Transform::from_translation(Vec3::X * 5.0),
+                    CollisionShape::Sphere { radius: 4.0 },
+                ),
+            ]),
+        ))
+        .id();
+
+    let sphere = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 2.0 },
+        ))
+        .id();
+
+    let mut event_reader = app
+        .world
+        .get_resource::<Events<ContactDetails>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    let events = app.world.get_resource::<Events<ContactDetails>>().unwrap();
+    let details: Vec<ContactDetails> = event_reader.iter(&events).cloned().collect();
+
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].collision_shape_entities(), (compound, sphere));
+    let points = details[0].points();
+    assert!(points.iter().any(|point| point.normal.x > 0.9));
+    assert!(points.iter().any(|point| point.normal.x < -0.9));
+}
+
 fn collect_events(
     app: &App,
     reader: &mut ManualEventReader<CollisionEvent>,
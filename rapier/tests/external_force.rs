@@ -0,0 +1,142 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, ExternalForce, ForceSpace, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App, transform: Transform, force: ExternalForce) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            force,
+        ))
+        .id()
+}
+
+#[test]
+fn force_at_center_does_not_rotate_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(
+        &mut app,
+        Transform::default(),
+        ExternalForce {
+            force: Vec3::X * 10.0,
+            ..Default::default()
+        },
+    );
+
+    app.update();
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert!(body.linvel().into_bevy().x > 0.0);
+
+    #[cfg(dim2)]
+    assert_eq!(body.angvel(), 0.0);
+    #[cfg(dim3)]
+    assert_eq!(body.angvel().into_bevy(), Vec3::ZERO);
+}
+
+#[test]
+fn force_at_offset_rotates_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(
+        &mut app,
+        Transform::default(),
+        ExternalForce {
+            force: Vec3::X * 10.0,
+            point: Vec3::Y,
+            ..Default::default()
+        },
+    );
+
+    app.update();
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    #[cfg(dim2)]
+    assert!(body.angvel() < 0.0);
+    #[cfg(dim3)]
+    assert!(body.angvel().z < 0.0);
+}
+
+#[test]
+fn local_force_turns_with_the_body() {
+    let mut app = test_app();
+
+    let transform = Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+    let local = spawn_body(
+        &mut app,
+        transform,
+        ExternalForce {
+            force: Vec3::X * 10.0,
+            space: ForceSpace::Local,
+            ..Default::default()
+        },
+    );
+    let world = spawn_body(
+        &mut app,
+        Transform {
+            translation: Vec3::Y * 100.0,
+            ..transform
+        },
+        ExternalForce {
+            force: Vec3::X * 10.0,
+            space: ForceSpace::World,
+            ..Default::default()
+        },
+    );
+
+    app.update();
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let local_velocity: Vec3 = bodies
+        .get(*app.world.get::<RigidBodyHandle>(local).unwrap())
+        .unwrap()
+        .linvel()
+        .into_bevy();
+    let world_velocity: Vec3 = bodies
+        .get(*app.world.get::<RigidBodyHandle>(world).unwrap())
+        .unwrap()
+        .linvel()
+        .into_bevy();
+
+    assert!(local_velocity.y > 0.0);
+    assert!(local_velocity.x.abs() < 0.001);
+    assert!(world_velocity.x > 0.0);
+    assert!(world_velocity.y.abs() < 0.001);
+}
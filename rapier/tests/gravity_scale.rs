@@ -0,0 +1,82 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Gravity, GravityScale, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .insert_resource(Gravity::from(Vec3::new(0.0, -10.0, 0.0)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App, translation: Vec3, scale: Option<GravityScale>) -> Entity {
+    let mut entity = app.world.spawn();
+    entity.insert_bundle((
+        GlobalTransform::from_translation(translation),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+    ));
+    if let Some(scale) = scale {
+        entity.insert(scale);
+    }
+    entity.id()
+}
+
+fn linear_velocity(app: &App, entity: Entity) -> Vec3 {
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+    body.linvel().into_bevy()
+}
+
+#[test]
+fn body_is_created_with_gravity_scale() {
+    let mut app = test_app();
+
+    let normal = spawn_body(&mut app, Vec3::ZERO, None);
+    let heavy = spawn_body(&mut app, Vec3::X * 10.0, Some(GravityScale(2.0)));
+    let floating = spawn_body(&mut app, Vec3::X * 20.0, Some(GravityScale(0.0)));
+
+    app.update();
+    app.update();
+
+    let normal_velocity = linear_velocity(&app, normal);
+    assert!(normal_velocity.y < 0.0);
+    assert!((linear_velocity(&app, heavy).y - 2.0 * normal_velocity.y).abs() < 0.001);
+    assert_eq!(linear_velocity(&app, floating), Vec3::ZERO);
+}
+
+#[test]
+fn gravity_scale_is_updated_without_recreating_the_body() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, Vec3::ZERO, Some(GravityScale(1.0)));
+
+    app.update();
+
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+    app.world.get_mut::<GravityScale>(entity).unwrap().0 = 3.0;
+
+    app.update();
+
+    assert_eq!(*app.world.get::<RigidBodyHandle>(entity).unwrap(), handle);
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    assert_eq!(bodies.get(handle).unwrap().gravity_scale(), 3.0);
+}
@@ -0,0 +1,153 @@
+#![cfg(any(dim2, dim3))]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bevy::app::Events;
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    ActivePhysicsHooks, CollisionData, CollisionEvent, CollisionShape, PhysicsSteps, RigidBody,
+};
+use heron_rapier::{
+    ContactModification, PhysicsHooks, PhysicsHooksResource, RapierPlugin, SolverContact,
+};
+
+struct IgnoreAll;
+
+impl PhysicsHooks for IgnoreAll {
+    fn filter_contact_pair(&self, _: &CollisionData, _: &CollisionData) -> bool {
+        false
+    }
+
+    fn modify_solver_contacts(&self, context: &mut ContactModification<'_, '_>) {
+        context.retain_solver_contacts(|_| false);
+    }
+}
+
+/// Set the tangent velocity of the contacts, and record them
+#[derive(Default, Clone)]
+struct RecordContacts(Arc<Mutex<Vec<SolverContact>>>);
+
+impl PhysicsHooks for RecordContacts {
+    fn modify_solver_contacts(&self, context: &mut ContactModification<'_, '_>) {
+        context.update_solver_contacts(|contact| contact.tangent_velocity = Vec3::Y);
+        self.0.lock().unwrap().extend(context.solver_contacts());
+    }
+}
+
+fn test_app() -> App {
+    test_app_with_hooks(IgnoreAll)
+}
+
+fn test_app_with_hooks(hooks: impl PhysicsHooks) -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .insert_resource(PhysicsHooksResource::new(hooks))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_overlapping_bodies(app: &mut App, hooks: Option<ActivePhysicsHooks>) -> Entity {
+    app.world.spawn().insert_bundle((
+        GlobalTransform::default(),
+        RigidBody::Static,
+        CollisionShape::Sphere { radius: 10.0 },
+    ));
+
+    let mut dynamic = app.world.spawn();
+    dynamic.insert_bundle((
+        Transform::from_translation(Vec3::X * 15.0),
+        GlobalTransform::from_translation(Vec3::X * 15.0),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 10.0 },
+    ));
+
+    if let Some(hooks) = hooks {
+        dynamic.insert(hooks);
+    }
+
+    dynamic.id()
+}
+
+fn collision_events(app: &mut App) -> Vec<CollisionEvent> {
+    let mut reader = app
+        .world
+        .get_resource::<Events<CollisionEvent>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    let events = app.world.get_resource::<Events<CollisionEvent>>().unwrap();
+    reader.iter(&events).copied().collect()
+}
+
+#[test]
+fn hooks_are_not_called_for_collision_shapes_without_active_hooks() {
+    let mut app = test_app();
+    spawn_overlapping_bodies(&mut app, None);
+
+    assert_eq!(collision_events(&mut app).len(), 1);
+}
+
+#[test]
+fn filtered_contact_pairs_do_not_collide() {
+    let mut app = test_app();
+    spawn_overlapping_bodies(
+        &mut app,
+        Some(ActivePhysicsHooks {
+            filter_contact_pairs: true,
+            ..ActivePhysicsHooks::default()
+        }),
+    );
+
+    assert!(collision_events(&mut app).is_empty());
+}
+
+#[test]
+fn solver_contacts_can_be_modified() {
+    let mut app = test_app();
+    let entity = spawn_overlapping_bodies(
+        &mut app,
+        Some(ActivePhysicsHooks {
+            modify_solver_contacts: true,
+            ..ActivePhysicsHooks::default()
+        }),
+    );
+
+    app.update();
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert_eq!(translation.x, 15.0);
+}
+
+#[test]
+fn solver_contacts_can_be_updated() {
+    let hooks = RecordContacts::default();
+    let mut app = test_app_with_hooks(hooks.clone());
+    spawn_overlapping_bodies(
+        &mut app,
+        Some(ActivePhysicsHooks {
+            modify_solver_contacts: true,
+            ..ActivePhysicsHooks::default()
+        }),
+    );
+
+    app.update();
+
+    let contacts = hooks.0.lock().unwrap();
+    assert!(!contacts.is_empty());
+    for contact in contacts.iter() {
+        assert_eq!(contact.tangent_velocity, Vec3::Y);
+        // The spheres overlap between `x = 5` and `x = 10`
+        assert!(contact.point.x >= 5.0 && contact.point.x <= 10.0);
+        assert!(contact.distance < 0.0);
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Impulse, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn linear_velocity(app: &App, entity: Entity) -> Vec3 {
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+    body.linvel().into_bevy()
+}
+
+#[test]
+fn impulse_is_applied_once_and_removed() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_linear(Vec3::X * 10.0));
+
+    app.update();
+
+    let velocity = linear_velocity(&app, entity);
+    assert!(velocity.x > 0.0);
+    assert!(app.world.get::<Impulse>(entity).is_none());
+
+    app.update();
+
+    assert_eq!(linear_velocity(&app, entity), velocity);
+}
+
+#[test]
+fn impulse_is_applied_after_the_body_is_created() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_linear(Vec3::X * 10.0));
+
+    app.update();
+    app.update();
+
+    assert!(linear_velocity(&app, entity).x > 0.0);
+    assert!(app.world.get::<Impulse>(entity).is_none());
+}
+
+#[test]
+fn impulse_at_point_causes_rotation() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_linear(Vec3::X * 10.0).at_point(Vec3::Y));
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    #[cfg(dim2)]
+    assert!(body.angvel() < 0.0);
+    #[cfg(dim3)]
+    assert!(body.angvel().z < 0.0);
+}
+
+#[test]
+fn impulse_is_added_to_the_velocity_component() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(Velocity::default());
+
+    app.update();
+
+    app.world.get_mut::<Velocity>(entity).unwrap().linear = Vec3::Y;
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_linear(Vec3::X * 10.0));
+
+    app.update();
+
+    let velocity = linear_velocity(&app, entity);
+    assert!(velocity.x > 0.0);
+    assert!((velocity.y - 1.0).abs() < 0.001);
+    assert_eq!(app.world.get::<Velocity>(entity).unwrap().linear, velocity);
+}
@@ -0,0 +1,86 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, TransformInterpolation, Velocity};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+            TransformInterpolation,
+        ))
+        .id()
+}
+
+fn body_translation(app: &App, entity: Entity) -> Vec3 {
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+    let (translation, _) = body.position().into_bevy();
+    translation
+}
+
+#[test]
+fn interpolated_transform_does_not_affect_simulation() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(body_translation(&app, entity).x, 3.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        3.0
+    );
+}
+
+#[test]
+fn body_can_be_teleported() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    app.world
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation
+        .x = 10.0;
+    app.update();
+
+    assert_eq!(body_translation(&app, entity).x, 11.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        11.0
+    );
+}
@@ -0,0 +1,200 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Joint, JointKind, PhysicMaterial, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App, body: RigidBody) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            body,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn ball_joint(body1: Entity, body2: Entity) -> Joint {
+    Joint::new(
+        body1,
+        body2,
+        JointKind::Ball {
+            local_anchor1: Vec3::X,
+            local_anchor2: -Vec3::X,
+        },
+    )
+}
+
+#[test]
+fn joint_is_created_between_bodies() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let body2 = spawn_body(&mut app, RigidBody::Dynamic);
+    let joint = app.world.spawn().insert(ball_joint(body1, body2)).id();
+
+    app.update();
+
+    let joints = app.world.get_resource::<JointSet>().unwrap();
+    let handle = app
+        .world
+        .get::<JointHandle>(joint)
+        .expect("No joint handle inserted");
+    let rapier_joint = joints.get(*handle).expect("No joint in the joint set");
+
+    assert_eq!(
+        rapier_joint.body1,
+        *app.world.get::<RigidBodyHandle>(body1).unwrap()
+    );
+    assert_eq!(
+        rapier_joint.body2,
+        *app.world.get::<RigidBodyHandle>(body2).unwrap()
+    );
+}
+
+#[test]
+fn joint_is_removed_with_component() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let body2 = spawn_body(&mut app, RigidBody::Dynamic);
+    let joint = app.world.spawn().insert(ball_joint(body1, body2)).id();
+
+    app.update();
+
+    app.world.entity_mut(joint).remove::<Joint>();
+
+    app.update();
+
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 0);
+    assert!(app.world.get::<JointHandle>(joint).is_none());
+}
+
+#[test]
+fn joint_is_removed_when_body_is_despawned() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let body2 = spawn_body(&mut app, RigidBody::Dynamic);
+    let joint = app.world.spawn().insert(ball_joint(body1, body2)).id();
+
+    app.update();
+
+    app.world.despawn(body2);
+
+    app.update();
+
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 0);
+    assert!(app.world.get::<JointHandle>(joint).is_none());
+}
+
+#[test]
+fn joint_can_be_updated() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let body2 = spawn_body(&mut app, RigidBody::Dynamic);
+    let body3 = spawn_body(&mut app, RigidBody::Dynamic);
+    let joint = app.world.spawn().insert(ball_joint(body1, body2)).id();
+
+    app.update();
+
+    app.world.get_mut::<Joint>(joint).unwrap().body2 = body3;
+
+    app.update();
+
+    let joints = app.world.get_resource::<JointSet>().unwrap();
+    let rapier_joint = joints
+        .get(*app.world.get::<JointHandle>(joint).unwrap())
+        .unwrap();
+
+    assert_eq!(joints.len(), 1);
+    assert_eq!(
+        rapier_joint.body2,
+        *app.world.get::<RigidBodyHandle>(body3).unwrap()
+    );
+}
+
+#[test]
+fn joint_is_recreated_with_body() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let body2 = spawn_body(&mut app, RigidBody::Dynamic);
+    let joint = app.world.spawn().insert(ball_joint(body1, body2)).id();
+
+    app.update();
+
+    app.world.entity_mut(body2).insert(PhysicMaterial {
+        density: 2.0,
+        ..Default::default()
+    });
+
+    app.update();
+
+    let joints = app.world.get_resource::<JointSet>().unwrap();
+    let rapier_joint = joints
+        .get(*app.world.get::<JointHandle>(joint).unwrap())
+        .unwrap();
+
+    assert_eq!(joints.len(), 1);
+    assert_eq!(
+        rapier_joint.body2,
+        *app.world.get::<RigidBodyHandle>(body2).unwrap()
+    );
+}
+
+#[test]
+#[cfg(dim3)]
+fn prismatic_joint_keeps_the_rotation_of_rotated_bodies() {
+    let mut app = test_app();
+
+    let body1 = spawn_body(&mut app, RigidBody::Static);
+    let rotation = Quat::from_rotation_x(std::f32::consts::FRAC_PI_2);
+    let body2 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_rotation(rotation),
+            GlobalTransform::from_rotation(rotation),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.world.spawn().insert(Joint::new(
+        body1,
+        body2,
+        JointKind::Prismatic {
+            local_anchor1: Vec3::ZERO,
+            local_axis1: Vec3::X,
+            local_anchor2: Vec3::ZERO,
+            local_axis2: Vec3::X,
+        },
+    ));
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let actual = app.world.get::<Transform>(body2).unwrap().rotation;
+    assert!(actual.dot(rotation).abs() > 0.999, "{:?}", actual);
+}
@@ -0,0 +1,189 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, MassProperties, PhysicMaterial, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn mass_properties() -> MassProperties {
+    MassProperties {
+        mass: 5.0,
+        local_center_of_mass: Vec3::new(0.0, -1.0, 0.0),
+        principal_inertia: Vec3::ONE,
+    }
+}
+
+#[test]
+fn body_is_created_with_mass_properties() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            PhysicMaterial {
+                density: 2.0,
+                ..Default::default()
+            },
+            mass_properties(),
+        ))
+        .id();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert!((body.mass() - 5.0).abs() < 0.001);
+
+    let center: Vec3 = body.mass_properties().local_com.coords.into_bevy();
+    assert!((center - Vec3::new(0.0, -1.0, 0.0)).length() < 0.001);
+}
+
+#[test]
+fn mass_properties_can_be_updated() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            mass_properties(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world.get_mut::<MassProperties>(entity).unwrap().mass = 10.0;
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert!((body.mass() - 10.0).abs() < 0.001);
+}
+
+#[test]
+fn mass_is_derived_from_density_when_mass_properties_are_removed() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            PhysicMaterial {
+                density: 2.0,
+                ..Default::default()
+            },
+            mass_properties(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<MassProperties>();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert_eq!(
+        body.mass_properties(),
+        &utils::MassProperties::from_ball(2.0, 1.0)
+    );
+}
+
+#[test]
+fn density_of_child_collision_shapes_is_ignored() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            mass_properties(),
+        ))
+        .with_children(|children| {
+            children.spawn_bundle((
+                Transform::from_xyz(2.0, 0.0, 0.0),
+                GlobalTransform::from_xyz(2.0, 0.0, 0.0),
+                CollisionShape::Sphere { radius: 1.0 },
+                PhysicMaterial {
+                    density: 100.0,
+                    ..Default::default()
+                },
+            ));
+        })
+        .id();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let body = bodies
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap();
+
+    assert_eq!(body.colliders().len(), 2);
+    assert!((body.mass() - 5.0).abs() < 0.001);
+
+    let center: Vec3 = body.mass_properties().local_com.coords.into_bevy();
+    assert!((center - Vec3::new(0.0, -1.0, 0.0)).length() < 0.001);
+}
+
+#[test]
+fn mass_properties_component_is_not_inserted() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+
+    assert!(app.world.get::<MassProperties>(entity).is_none());
+}
@@ -0,0 +1,78 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, OneWayPlatform, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_platform(app: &mut App, rotation: Quat) {
+    app.world.spawn().insert_bundle((
+        GlobalTransform::from_rotation(rotation),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(10.0, 0.5, 10.0),
+            border_radius: None,
+        },
+        OneWayPlatform::default(),
+    ));
+}
+
+/// Spawn a body overlapping the platform, and returns its `y` coordinate after a few steps
+fn simulate_body_at(app: &mut App, y: f32) -> f32 {
+    let translation = Vec3::Y * y;
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    app.world.get::<Transform>(entity).unwrap().translation.y
+}
+
+#[test]
+fn body_is_blocked_on_the_allowed_side() {
+    let mut app = test_app();
+    spawn_platform(&mut app, Quat::IDENTITY);
+
+    assert!(simulate_body_at(&mut app, 1.2) > 1.2);
+}
+
+#[test]
+fn body_can_pass_through_from_the_other_side() {
+    let mut app = test_app();
+    spawn_platform(&mut app, Quat::IDENTITY);
+
+    assert_eq!(simulate_body_at(&mut app, -1.2), -1.2);
+}
+
+#[test]
+fn allowed_direction_follows_the_platform_rotation() {
+    let mut app = test_app();
+    spawn_platform(&mut app, Quat::from_rotation_z(std::f32::consts::PI));
+
+    assert_eq!(simulate_body_at(&mut app, 1.2), 1.2);
+    assert!(simulate_body_at(&mut app, -1.2) < -1.2);
+}
@@ -6,7 +6,7 @@ use bevy::core::CorePlugin;
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 
-use heron_core::{CollisionShape, PhysicMaterial, PhysicsSteps, RigidBody};
+use heron_core::{CollisionShape, CombineRule, PhysicMaterial, PhysicsSteps, RigidBody};
 use heron_rapier::RapierPlugin;
 use utils::*;
 
@@ -78,3 +78,32 @@ fn restitution_can_be_updated() {
 
     assert_eq!(restitution, collider.restitution())
 }
+
+#[test]
+fn restitution_combine_rule_can_be_defined() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            PhysicMaterial {
+                restitution_combine_rule: CombineRule::Max,
+                ..Default::default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders.get(*app.world.get(entity).unwrap()).unwrap();
+
+    assert_eq!(
+        collider.material().restitution_combine_rule,
+        CoefficientCombineRule::Max
+    );
+}
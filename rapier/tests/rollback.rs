@@ -0,0 +1,199 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+#[cfg(feature = "serde")]
+use heron_core::Sleeping;
+use heron_core::{CollisionShape, Gravity, PhysicsSteps, RigidBody, Velocity};
+#[cfg(feature = "serde")]
+use heron_rapier::PhysicsSnapshot;
+use heron_rapier::{PhysicsStepper, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::manual(Duration::from_secs_f32(1.0 / 60.0)))
+        .insert_resource(Gravity::from(Vec3::Y * -9.81))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+#[cfg(feature = "serde")]
+fn spawn_ground(app: &mut App) {
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(10.0, 0.5, 10.0),
+            border_radius: None,
+        },
+    ));
+}
+
+fn spawn_box(app: &mut App, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::Dynamic,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::splat(0.5),
+                border_radius: None,
+            },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+#[cfg(feature = "serde")]
+fn simulate(app: &mut App, stepper: &mut PhysicsStepper, entity: Entity, inputs: &[Vec3]) {
+    for input in inputs {
+        app.world.get_mut::<Velocity>(entity).unwrap().linear += *input;
+        stepper.step(&mut app.world);
+    }
+}
+
+/// Complete state of the simulation: the serialized physics world, and the components of the bodies
+#[cfg(feature = "serde")]
+fn state(app: &mut App) -> (Vec<u8>, Vec<(Entity, Transform, Velocity, bool)>) {
+    let physics_world = bincode::serialize(&PhysicsSnapshot::take(&app.world)).unwrap();
+    let mut components: Vec<_> = app
+        .world
+        .query_filtered::<(Entity, &Transform, &Velocity, Option<&Sleeping>), With<RigidBody>>()
+        .iter(&app.world)
+        .map(|(entity, transform, velocity, sleeping)| {
+            (entity, *transform, *velocity, sleeping.is_some())
+        })
+        .collect();
+    components.sort_by_key(|(entity, ..)| *entity);
+    (physics_world, components)
+}
+
+#[test]
+fn manual_steps_are_not_performed_automatically() {
+    let mut app = test_app();
+    let entity = spawn_box(&mut app, Vec3::Y * 5.0);
+
+    app.update();
+    app.update();
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::Y * 5.0
+    );
+
+    let mut stepper = PhysicsStepper::new();
+    stepper.step(&mut app.world);
+    stepper.step(&mut app.world);
+    app.update();
+
+    assert!(app.world.get::<Transform>(entity).unwrap().translation.y < 5.0);
+}
+
+#[test]
+fn kinematic_velocity_uses_step_duration() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 0.5 },
+            Velocity::from_linear(Vec3::X * 60.0),
+        ))
+        .id();
+
+    let mut stepper = PhysicsStepper::new();
+    for _ in 0..3 {
+        stepper.step(&mut app.world);
+    }
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - 3.0).abs() < 0.001);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn resimulation_is_identical() {
+    let mut app = test_app();
+    spawn_ground(&mut app);
+    let entity = spawn_box(&mut app, Vec3::Y * 1.2);
+    let rotated = spawn_box(&mut app, Vec3::new(0.3, 2.4, 0.0));
+    app.world
+        .get_mut::<Transform>(rotated)
+        .unwrap()
+        .rotate(Quat::from_rotation_z(0.3));
+    let sleeping = spawn_box(&mut app, Vec3::new(4.0, 5.0, 0.0));
+    app.world.entity_mut(sleeping).insert(Sleeping);
+
+    let mut stepper = PhysicsStepper::new();
+
+    // Let the boxes land on the ground, so that there are contacts in the snapshot
+    for _ in 0..30 {
+        stepper.step(&mut app.world);
+    }
+
+    let snapshot = PhysicsSnapshot::take(&app.world);
+    let inputs = [Vec3::X, Vec3::ZERO, Vec3::Y * 2.0, Vec3::ZERO, -Vec3::X];
+    let mut run = |app: &mut App| {
+        simulate(app, &mut stepper, entity, &inputs);
+
+        // The sleeping box floats in the air until it is moved
+        app.world
+            .get_mut::<Transform>(sleeping)
+            .unwrap()
+            .translation
+            .x += 1.0;
+        stepper.step(&mut app.world);
+        state(app)
+    };
+
+    let original = run(&mut app);
+    snapshot.restore(&mut app.world);
+    let resimulated = run(&mut app);
+
+    assert!(
+        original.0 == resimulated.0,
+        "The physics worlds are different"
+    );
+    assert_eq!(original.1, resimulated.1);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn resimulation_with_a_new_stepper_is_identical() {
+    let mut app = test_app();
+    spawn_ground(&mut app);
+    let entity = spawn_box(&mut app, Vec3::Y * 1.2);
+    spawn_box(&mut app, Vec3::new(0.3, 2.4, 0.0));
+
+    let mut stepper = PhysicsStepper::new();
+    for _ in 0..30 {
+        stepper.step(&mut app.world);
+    }
+
+    let snapshot = PhysicsSnapshot::take(&app.world);
+    let inputs = [Vec3::X, Vec3::ZERO, -Vec3::X];
+
+    simulate(&mut app, &mut stepper, entity, &inputs);
+    let original = state(&mut app);
+
+    snapshot.restore(&mut app.world);
+    simulate(&mut app, &mut PhysicsStepper::new(), entity, &inputs);
+    let resimulated = state(&mut app);
+
+    assert!(
+        original.0 == resimulated.0,
+        "The physics worlds are different"
+    );
+    assert_eq!(original.1, resimulated.1);
+}
@@ -0,0 +1,137 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::app::Events;
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    BodySleep, BodyWake, CollisionShape, PhysicsSteps, RigidBody, SleepThreshold, Sleeping,
+};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn is_sleeping(app: &App, entity: Entity) -> bool {
+    app.world
+        .get_resource::<RigidBodySet>()
+        .unwrap()
+        .get(*app.world.get::<RigidBodyHandle>(entity).unwrap())
+        .unwrap()
+        .is_sleeping()
+}
+
+#[test]
+fn body_can_be_forced_to_sleep() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    let mut reader = app
+        .world
+        .get_resource::<Events<BodySleep>>()
+        .unwrap()
+        .get_reader();
+
+    app.world.entity_mut(entity).insert(Sleeping);
+
+    app.update();
+
+    assert!(is_sleeping(&app, entity));
+    assert!(app.world.get::<Sleeping>(entity).is_some());
+
+    let events = app.world.get_resource::<Events<BodySleep>>().unwrap();
+    let events: Vec<BodySleep> = reader.iter(&events).copied().collect();
+    assert_eq!(events, vec![BodySleep(entity)]);
+}
+
+#[test]
+fn body_can_be_woken_up() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(Sleeping);
+
+    app.update();
+
+    assert!(is_sleeping(&app, entity));
+
+    let mut reader = app
+        .world
+        .get_resource::<Events<BodyWake>>()
+        .unwrap()
+        .get_reader();
+
+    app.world.entity_mut(entity).remove::<Sleeping>();
+
+    app.update();
+
+    assert!(!is_sleeping(&app, entity));
+
+    let events = app.world.get_resource::<Events<BodyWake>>().unwrap();
+    let events: Vec<BodyWake> = reader.iter(&events).copied().collect();
+    assert_eq!(events, vec![BodyWake(entity)]);
+}
+
+#[test]
+fn sleeping_component_is_inserted_when_body_falls_asleep() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+
+    for _ in 0..200 {
+        app.update();
+    }
+
+    assert!(is_sleeping(&app, entity));
+    assert!(app.world.get::<Sleeping>(entity).is_some());
+}
+
+#[test]
+fn body_does_not_sleep_if_threshold_is_negative() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(SleepThreshold::NEVER);
+
+    for _ in 0..200 {
+        app.update();
+    }
+
+    assert!(!is_sleeping(&app, entity));
+    assert!(app.world.get::<Sleeping>(entity).is_none());
+}
+
+#[test]
+fn default_threshold_is_the_one_of_rapier() {
+    assert_eq!(
+        SleepThreshold::default(),
+        SleepThreshold(RigidBodyActivation::default_threshold())
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(all(any(dim2, dim3), feature = "serde"))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::entity::{EntityMap, MapEntities};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::{PhysicsSnapshot, RapierPlugin};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id()
+}
+
+fn translation(app: &App, entity: Entity) -> Vec3 {
+    app.world.get::<Transform>(entity).unwrap().translation
+}
+
+#[test]
+fn restore_snapshot() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    let snapshot = PhysicsSnapshot::take(&app.world);
+
+    app.update();
+    app.update();
+    assert_eq!(translation(&app, entity).x, 3.0);
+
+    snapshot.restore(&mut app.world);
+    assert_eq!(translation(&app, entity).x, 1.0);
+
+    app.update();
+    assert_eq!(translation(&app, entity).x, 2.0);
+}
+
+#[test]
+fn restore_snapshot_on_new_entities() {
+    let mut app = test_app();
+    let old_entity = spawn_body(&mut app);
+    app.update();
+
+    let mut snapshot = PhysicsSnapshot::take(&app.world);
+
+    app.world.despawn(old_entity);
+    let new_entity = spawn_body(&mut app);
+
+    let mut entity_map = EntityMap::default();
+    entity_map.insert(old_entity, new_entity);
+    snapshot.map_entities(&entity_map).unwrap();
+    snapshot.restore(&mut app.world);
+
+    assert_eq!(translation(&app, new_entity).x, 1.0);
+    assert!(app.world.get::<RigidBodyHandle>(new_entity).is_some());
+
+    app.update();
+    assert_eq!(translation(&app, new_entity).x, 2.0);
+    assert_eq!(app.world.get_resource::<RigidBodySet>().unwrap().len(), 1);
+}
+
+#[test]
+fn serialized_snapshot_can_be_restored() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    let bytes = bincode::serialize(&PhysicsSnapshot::take(&app.world)).unwrap();
+
+    app.update();
+    app.update();
+
+    let snapshot: PhysicsSnapshot = bincode::deserialize(&bytes).unwrap();
+    snapshot.restore(&mut app.world);
+    assert_eq!(translation(&app, entity).x, 1.0);
+
+    app.update();
+    assert_eq!(translation(&app, entity).x, 2.0);
+}
+
+#[test]
+fn cloned_snapshot_can_be_restored_several_times() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    let snapshot = PhysicsSnapshot::take(&app.world);
+
+    for _ in 0..2 {
+        app.update();
+        snapshot.clone().restore(&mut app.world);
+        assert_eq!(translation(&app, entity).x, 1.0);
+    }
+}
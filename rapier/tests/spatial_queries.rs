@@ -0,0 +1,234 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::schedule::Stage;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{PhysicsWorld, RapierPlugin, RayCastInfo, ShapeCastCollisionType};
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+/// Spawn a static body with the given shape at the origin, and add it to the physics world
+fn spawn_shape(shape: CollisionShape) -> (App, Entity) {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((GlobalTransform::identity(), RigidBody::Static, shape))
+        .id();
+
+    app.update();
+    (app, entity)
+}
+
+struct RayCast(Option<RayCastInfo>);
+
+/// Cast a ray from `y = 5` down to `y = -5`
+fn ray_cast_down(app: &mut App) -> Option<RayCastInfo> {
+    fn system(physics_world: PhysicsWorld<'_>, mut result: ResMut<'_, RayCast>) {
+        result.0 = physics_world.ray_cast(Vec3::Y * 5.0, Vec3::Y * -10.0, true);
+    }
+
+    app.world.insert_resource(RayCast(None));
+    SystemStage::single_threaded()
+        .with_system(system.system())
+        .run(&mut app.world);
+    app.world.remove_resource::<RayCast>().unwrap().0
+}
+
+struct ShapeCast(Option<(Entity, Vec3)>);
+
+/// Cast a sphere of radius `0.5` from `y = 5` down to `y = -5`
+///
+/// Returns the collided entity and the position of the sphere at the collision
+fn sphere_cast_down(app: &mut App) -> Option<(Entity, Vec3)> {
+    fn system(physics_world: PhysicsWorld<'_>, mut result: ResMut<'_, ShapeCast>) {
+        let shape = CollisionShape::Sphere { radius: 0.5 };
+        result.0 = physics_world
+            .shape_cast(&shape, Vec3::Y * 5.0, Quat::IDENTITY, Vec3::Y * -10.0)
+            .map(|info| match info.collision_type {
+                ShapeCastCollisionType::Collided(collision) => {
+                    (info.entity, collision.self_end_position)
+                }
+                ShapeCastCollisionType::AlreadyPenetrating => panic!("Unexpected penetration"),
+            });
+    }
+
+    app.world.insert_resource(ShapeCast(None));
+    SystemStage::single_threaded()
+        .with_system(system.system())
+        .run(&mut app.world);
+    app.world.remove_resource::<ShapeCast>().unwrap().0
+}
+
+/// Assert that the ray and sphere casts going down hit the shape, whose top is at `y = top`
+fn assert_casts_hit(shape: CollisionShape, top: f32) {
+    let (mut app, entity) = spawn_shape(shape);
+
+    let ray = ray_cast_down(&mut app).expect("The ray didn't hit the shape");
+    assert_eq!(ray.entity, entity);
+    assert!((ray.collision_point.y - top).abs() < 0.001);
+    assert!((ray.normal - Vec3::Y).length() < 0.001);
+
+    let (sphere_entity, sphere_position) =
+        sphere_cast_down(&mut app).expect("The sphere didn't hit the shape");
+    assert_eq!(sphere_entity, entity);
+    assert!((sphere_position.y - (top + 0.5)).abs() < 0.001);
+}
+
+#[test]
+fn casts_hit_tri_mesh() {
+    #[cfg(dim2)]
+    let vertices = vec![
+        Vec3::new(-1.0, -1.0, 0.0),
+        Vec3::new(1.0, -1.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(-1.0, 0.0, 0.0),
+    ];
+
+    #[cfg(dim3)]
+    let vertices = vec![
+        Vec3::new(-1.0, 0.0, -1.0),
+        Vec3::new(1.0, 0.0, -1.0),
+        Vec3::new(1.0, 0.0, 1.0),
+        Vec3::new(-1.0, 0.0, 1.0),
+    ];
+
+    assert_casts_hit(
+        CollisionShape::TriMesh {
+            vertices,
+            indices: vec![[0, 1, 2], [0, 2, 3]],
+        },
+        0.0,
+    );
+}
+
+#[test]
+fn casts_hit_triangle() {
+    #[cfg(dim2)]
+    let shape = CollisionShape::Triangle {
+        a: Vec3::new(-1.0, 0.0, 0.0),
+        b: Vec3::new(0.0, -1.0, 0.0),
+        c: Vec3::new(1.0, 0.0, 0.0),
+    };
+
+    #[cfg(dim3)]
+    let shape = CollisionShape::Triangle {
+        a: Vec3::new(-1.0, 0.0, -1.0),
+        b: Vec3::new(1.0, 0.0, -1.0),
+        c: Vec3::new(0.0, 0.0, 1.0),
+    };
+
+    assert_casts_hit(shape, 0.0);
+}
+
+#[test]
+fn casts_hit_half_space() {
+    assert_casts_hit(
+        CollisionShape::HalfSpace {
+            outward_normal: Vec3::Y,
+        },
+        0.0,
+    );
+}
+
+#[test]
+#[cfg(dim3)]
+fn casts_hit_cylinder() {
+    assert_casts_hit(
+        CollisionShape::Cylinder {
+            half_height: 1.0,
+            radius: 1.0,
+        },
+        1.0,
+    );
+}
+
+#[test]
+#[cfg(dim3)]
+fn sphere_cast_hits_cone() {
+    let (mut app, entity) = spawn_shape(CollisionShape::Cone {
+        half_height: 1.0,
+        radius: 1.0,
+    });
+
+    // The sphere touches the apex of the cone
+    let (sphere_entity, sphere_position) = sphere_cast_down(&mut app).unwrap();
+    assert_eq!(sphere_entity, entity);
+    assert!((sphere_position.y - 1.5).abs() < 0.001);
+}
+
+#[test]
+#[cfg(dim2)]
+fn casts_hit_segment() {
+    assert_casts_hit(
+        CollisionShape::Segment {
+            a: Vec3::new(-1.0, 0.0, 0.0),
+            b: Vec3::new(1.0, 0.0, 0.0),
+        },
+        0.0,
+    );
+}
+
+#[test]
+#[cfg(dim2)]
+fn casts_hit_polyline() {
+    assert_casts_hit(
+        CollisionShape::Polyline {
+            vertices: vec![
+                Vec3::new(-2.0, 1.0, 0.0),
+                Vec3::new(-1.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(2.0, 1.0, 0.0),
+            ],
+            indices: None,
+        },
+        0.0,
+    );
+}
+
+/// In 3d, segments have no thickness, so only the shapes with a volume can hit them
+#[test]
+#[cfg(dim3)]
+fn sphere_cast_hits_segment() {
+    let (mut app, entity) = spawn_shape(CollisionShape::Segment {
+        a: Vec3::new(-1.0, 0.0, 0.0),
+        b: Vec3::new(1.0, 0.0, 0.0),
+    });
+
+    let (sphere_entity, sphere_position) = sphere_cast_down(&mut app).unwrap();
+    assert_eq!(sphere_entity, entity);
+    assert!((sphere_position.y - 0.5).abs() < 0.001);
+}
+
+/// In 3d, polylines have no thickness, so only the shapes with a volume can hit them
+#[test]
+#[cfg(dim3)]
+fn sphere_cast_hits_polyline() {
+    let (mut app, entity) = spawn_shape(CollisionShape::Polyline {
+        vertices: vec![
+            Vec3::new(-1.0, 0.0, -1.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, -1.0),
+        ],
+        indices: None,
+    });
+
+    let (sphere_entity, sphere_position) = sphere_cast_down(&mut app).unwrap();
+    assert_eq!(sphere_entity, entity);
+    assert!((sphere_position.y - 0.5).abs() < 0.001);
+}
@@ -0,0 +1,100 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{Acceleration, CollisionShape, ExternalForce, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)).with_substeps(4))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+#[test]
+fn integration_parameters_use_substep_duration() {
+    let mut app = test_app();
+    app.update();
+
+    let parameters = app.world.get_resource::<IntegrationParameters>().unwrap();
+    assert_eq!(parameters.dt, 0.25);
+}
+
+#[test]
+fn substeps_advance_the_simulation_by_the_step_duration() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - 2.0).abs() < 0.001);
+}
+
+fn velocity_after_force(substeps: u32) -> Vec3 {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(
+            PhysicsSteps::every_frame(Duration::from_secs_f32(0.1)).with_substeps(substeps),
+        )
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    let mut app = builder.app;
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+            ExternalForce {
+                force: Vec3::X * 10.0,
+                ..Default::default()
+            },
+            Acceleration::from_linear(Vec3::Y),
+        ))
+        .id();
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    app.world.get::<Velocity>(entity).unwrap().linear
+}
+
+#[test]
+fn forces_are_applied_at_every_substep() {
+    let with_one_substep = velocity_after_force(1);
+    let with_four_substeps = velocity_after_force(4);
+
+    assert!(with_one_substep.x > 0.0);
+    assert!(with_one_substep.y > 0.0);
+    assert!((with_one_substep - with_four_substeps).length() < 0.001);
+}
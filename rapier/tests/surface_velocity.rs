@@ -0,0 +1,85 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    CollisionShape, Gravity, PhysicMaterial, PhysicsSteps, RigidBody, SurfaceVelocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::build();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -9.81))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    builder.app
+}
+
+fn spawn_conveyor(app: &mut App, rotation: Quat) {
+    app.world.spawn().insert_bundle((
+        GlobalTransform::from_rotation(rotation),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(100.0, 0.5, 100.0),
+            border_radius: None,
+        },
+        PhysicMaterial {
+            friction: 1.0,
+            ..PhysicMaterial::default()
+        },
+        SurfaceVelocity(Vec3::X * 2.0),
+    ));
+}
+
+/// Spawn a box on the conveyor, and returns its `x` coordinate after one second
+fn simulate_box(app: &mut App) -> f32 {
+    let translation = Vec3::Y;
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::Dynamic,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::splat(0.5),
+                border_radius: None,
+            },
+            PhysicMaterial {
+                friction: 1.0,
+                ..PhysicMaterial::default()
+            },
+        ))
+        .id();
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    app.world.get::<Transform>(entity).unwrap().translation.x
+}
+
+#[test]
+fn bodies_are_dragged_by_surface_velocity() {
+    let mut app = test_app();
+    spawn_conveyor(&mut app, Quat::IDENTITY);
+
+    assert!(simulate_box(&mut app) > 0.5);
+}
+
+#[test]
+fn surface_velocity_follows_the_collision_shape_rotation() {
+    let mut app = test_app();
+    spawn_conveyor(&mut app, Quat::from_rotation_z(std::f32::consts::PI));
+
+    assert!(simulate_box(&mut app) < -0.5);
+}
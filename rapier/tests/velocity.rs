@@ -130,7 +130,6 @@ fn velocity_is_updated_to_reflect_rapier_world() {
         .id();
 
     app.update();
-    app.update();
 
     let velocity = app.world.get::<Velocity>(entity).unwrap();
 
@@ -220,3 +219,52 @@ fn z_components_is_preserved() {
 
     assert_eq!(5.0, actual_translation.z);
 }
+
+#[test]
+fn kinematic_velocity_is_scaled_by_physics_time() {
+    let mut app = test_app();
+    app.world.insert_resource(PhysicsTime::new(0.5));
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::from_linear(Vec3::X * 2.0),
+        ))
+        .id();
+
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - 1.0).abs() < 0.001);
+}
+
+#[test]
+fn kinematic_velocity_moves_the_body_at_every_step() {
+    let mut app = test_app();
+    app.world
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)).with_substeps(4));
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::from_linear(Vec3::X * 2.0),
+        ))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - 6.0).abs() < 0.001);
+}
@@ -32,6 +32,8 @@
 //! * `3d` Enable simulation on the 3 axes `x`, `y`, and `z`. Incompatible with the feature `2d`.
 //! * `2d` Enable simulation only on the first 2 axes `x` and `y`. Incompatible with the feature `3d`, therefore require to disable the default features.
 //! * `debug-2d` Render 2d collision shapes. Works only in 2d, support for 3d may be added later.
+//! * `collision-from-mesh` Generate collision shapes from bevy `Mesh` assets with the `ColliderFromMesh` component.
+//! * `serde-2d` / `serde-3d` Enable the `PhysicsSnapshot`, which can be serialized with serde. Imply the feature `2d` / `3d`.
 //!
 //! ## Install the plugin
 //!
@@ -114,10 +116,23 @@
 //! * How to define a [`RigidBody`]
 //! * How to choose a [`CollisionShape`]
 //! * How to define the world's [`Gravity`]
+//! * How to scale the gravity of a body with [`GravityScale`]
 //! * How to define the world's [`PhysicsTime`]
+//! * How to smooth the motion between physics steps with [`TransformInterpolation`]
 //! * How to define the [`PhysicMaterial`]
+//! * How to define the [`MassProperties`] explicitly
 //! * How to listen to [`CollisionEvent`]
+//! * How to get the contact points with [`ContactDetails`]
+//! * How to know when bodies are [`Sleeping`]
 //! * How to define [`RotationConstraints`]
+//! * How to let a body push others without being pushed with [`Dominance`]
+//! * How to slow bodies down with [`Damping`]
+//! * How to prevent fast bodies from tunneling with [`ContinuousCollisionDetection`]
+//! * How to make jump-through platforms with [`OneWayPlatform`]
+//! * How to make conveyor belts with [`SurfaceVelocity`]
+//! * How to connect two bodies with a [`Joint`]
+//! * How to move a character with the [`CharacterController`]
+//! * How to rollback and resimulate with the `PhysicsSnapshot` and the [`PhysicsStepper`](rapier_plugin::PhysicsStepper)
 
 use bevy::app::{AppBuilder, Plugin};
 
@@ -138,10 +153,17 @@ pub mod prelude {
 
     #[allow(deprecated)]
     pub use crate::{
-        ext::*, stage, Acceleration, AxisAngle, CollisionEvent, CollisionLayers, CollisionShape,
-        Gravity, PhysicMaterial, PhysicsLayer, PhysicsPlugin, PhysicsSystem, PhysicsTime,
-        RigidBody, RotationConstraints, Velocity,
+        ext::*, stage, Acceleration, ActivePhysicsHooks, AxisAngle, CharacterController,
+        CollisionEvent, CollisionLayers, CollisionShape, CombineRule, ContactDetails,
+        ContinuousCollisionDetection, ConvexDecompositionParameters, Damping, Dominance,
+        ExternalForce, ForceSpace, Gravity, GravityScale, Impulse, Joint, JointKind,
+        MassProperties, OneWayPlatform, PhysicMaterial, PhysicsLayer, PhysicsPlugin, PhysicsSystem,
+        PhysicsTime, RigidBody, RotationConstraints, SleepThreshold, Sleeping, SurfaceVelocity,
+        TransformInterpolation, Velocity,
     };
+
+    #[cfg(feature = "collision-from-mesh")]
+    pub use crate::{ColliderFromMesh, MeshColliderKind};
 }
 
 /// Plugin to install to enable collision detection and physics behavior.